@@ -11,11 +11,107 @@ pub const FAUCET_CONFIG_SEEDS: &str = "faucet_config";
 #[constant]
 pub const FAUCET_RECIPIENT_SEEDS: &str = "faucet_recipient";
 
+#[constant]
+pub const FAUCET_DEPOSITOR_SEEDS: &str = "faucet_depositor";
+
+#[constant]
+pub const FAUCET_RENT_VAULT_SEEDS: &str = "faucet_rent_vault";
+
+#[constant]
+pub const FAUCET_POOL_COUNTER_SEEDS: &str = "faucet_pool_counter";
+
+// Longest lock the owner can put on sponsor pool deposits.
+#[constant]
+pub const MAX_SPONSOR_LOCK_SECONDS: u64 = 365 * 24 * 60 * 60;
+
+#[constant]
+pub const SOL_FAUCET_CONFIG_SEEDS: &str = "sol_faucet_config";
+
+#[constant]
+pub const SOL_FAUCET_VAULT_SEEDS: &str = "sol_faucet_vault";
+
+#[constant]
+pub const SOL_FAUCET_RECIPIENT_SEEDS: &str = "sol_faucet_recipient";
+
+#[constant]
+pub const BUNDLE_FAUCET_CONFIG_SEEDS: &str = "bundle_faucet_config";
+
+#[constant]
+pub const BUNDLE_FAUCET_RECIPIENT_SEEDS: &str = "bundle_faucet_recipient";
+
+#[constant]
+pub const MAX_BUNDLE_ENTRIES: u8 = 5;
+
 #[constant]
 pub const FACTORY_CONFIG_SEEDS: &str = "factory_config";
 
 #[constant]
 pub const FACTORY_TREASURY: &str = "factory_treasury";
 
+#[constant]
+pub const FEE_MINT_CONFIG_SEEDS: &str = "fee_mint_config";
+
+#[constant]
+pub const CREATOR_STATS_SEEDS: &str = "creator_stats";
+
+#[constant]
+pub const FEE_DISCOUNT_SEEDS: &str = "fee_discount";
+
+#[constant]
+pub const PENDING_ACTION_SEEDS: &str = "pending_action";
+
+#[constant]
+pub const FACTORY_ROLE_SEEDS: &str = "factory_role";
+
+#[constant]
+pub const PROPOSAL_SEEDS: &str = "proposal";
+
+#[constant]
+pub const MAX_ADMIN_SIGNERS: u8 = 10;
+
+#[constant]
+pub const MAX_FEE_RECIPIENTS: u8 = 5;
+
+// Denominator for basis-point percentages.
+#[constant]
+pub const MAX_BPS: u16 = 10_000;
+
+#[constant]
+pub const PROGRAM_STATE_SEEDS: &str = "program_state";
+
+// Module flags for `ProgramState.paused_modules`.
+#[constant]
+pub const PAUSE_FACTORY: u8 = 1 << 0;
+
+#[constant]
+pub const PAUSE_TOKEN: u8 = 1 << 1;
+
+#[constant]
+pub const PAUSE_FAUCET: u8 = 1 << 2;
+
+#[constant]
+pub const PAUSE_SOL_FAUCET: u8 = 1 << 3;
+
+#[constant]
+pub const PAUSE_BUNDLE_FAUCET: u8 = 1 << 4;
+
+// Current account layout versions; bump when a field is added and extend the migrations.
+#[constant]
+pub const FACTORY_CONFIG_VERSION: u8 = 1;
+
+#[constant]
+pub const FAUCET_CONFIG_VERSION: u8 = 1;
+
+#[constant]
+pub const FAUCET_RECIPIENT_VERSION: u8 = 1;
+
+// Zeroed space kept at the end of config accounts for future fields.
+#[constant]
+pub const CONFIG_RESERVED_BYTES: usize = 64;
+
+// Recipient records exist once per wallet, so they keep a smaller reserve.
+#[constant]
+pub const RECIPIENT_RESERVED_BYTES: usize = 32;
+
 #[constant]
 pub const MAX_DECIMALS: u8 = 9;
@@ -22,6 +22,63 @@ pub enum FactoryError {
 
     #[msg("No funds available to withdraw.")]
     InsufficientFundsToWithdraw,
+
+    #[msg("The selected fee mint is not currently accepted.")]
+    FeeMintDisabled,
+
+    #[msg("Fee mint accounts are missing or do not match the fee mint configuration.")]
+    InvalidFeeMintAccounts,
+
+    #[msg("Basis point values must be at most 10,000.")]
+    InvalidBasisPoints,
+
+    #[msg("Too many fee recipients.")]
+    TooManyFeeRecipients,
+
+    #[msg("Fee recipient shares must be unique, non-zero and sum to 10,000 basis points.")]
+    InvalidFeeSplit,
+
+    #[msg("No fee recipients are configured.")]
+    NoFeeRecipients,
+
+    #[msg("Fee recipient accounts do not match the configured fee recipients.")]
+    InvalidFeeRecipientAccounts,
+
+    #[msg("The creator cannot refer themselves.")]
+    SelfReferral,
+
+    #[msg("This action must be queued through the timelock.")]
+    TimelockRequired,
+
+    #[msg("The timelock delay for this action has not elapsed yet.")]
+    TimelockNotElapsed,
+
+    #[msg("The accounts passed do not match the queued action.")]
+    InvalidActionAccounts,
+
+    #[msg("The creation fee exceeds the maximum the payer agreed to.")]
+    FeeExceedsMax,
+
+    #[msg("This action must be approved through a multisig proposal.")]
+    MultisigRequired,
+
+    #[msg("The signer is not in the factory admin set.")]
+    NotAdminSigner,
+
+    #[msg("The signer has already approved this proposal.")]
+    AlreadyApproved,
+
+    #[msg("The proposal does not have enough approvals yet.")]
+    ThresholdNotMet,
+
+    #[msg("Admin signers must be unique, at most 10, with a threshold between 1 and the signer count.")]
+    InvalidAdminSigners,
+
+    #[msg("Actions can only be queued while the timelock is enabled.")]
+    TimelockNotEnabled,
+
+    #[msg("The config account is not in a layout that can be migrated.")]
+    UnsupportedConfigLayout,
 }
 
 #[error_code]
@@ -40,4 +97,94 @@ pub enum FaucetError {
 
     #[msg("The provided decimals exceed the maximum allowed.")]
     ExceedsMaxDecimals,
+
+    #[msg("The bundle faucet already holds the maximum number of entries.")]
+    BundleFull,
+
+    #[msg("The mint is already part of the bundle faucet.")]
+    DuplicateBundleMint,
+
+    #[msg("The mint is not part of the bundle faucet.")]
+    BundleMintNotFound,
+
+    #[msg("The remaining accounts do not match the bundle entries.")]
+    InvalidBundleAccounts,
+
+    #[msg("Transfer fee calculation overflowed.")]
+    TransferFeeOverflow,
+
+    #[msg("Arithmetic overflow.")]
+    MathOverflow,
+
+    #[msg("The faucet is not in sponsored pool mode.")]
+    SponsoredPoolDisabled,
+
+    #[msg("Owner withdrawals are disabled while the sponsored pool is active.")]
+    SponsoredPoolActive,
+
+    #[msg("The sponsored pool still holds funds or shares.")]
+    PoolNotEmpty,
+
+    #[msg("Sponsor funds are still within the lock period.")]
+    SponsorLockActive,
+
+    #[msg("The depositor has no shares in the sponsored pool.")]
+    NoSponsorShares,
+
+    #[msg("Nothing has accrued to claim yet.")]
+    NothingToClaim,
+
+    #[msg("A drip rate requires a non-zero cap.")]
+    InvalidDripConfig,
+
+    #[msg("The faucet has reached its claim limit for the current epoch.")]
+    EpochClaimLimitReached,
+
+    #[msg("The faucet is currently paused.")]
+    FaucetPaused,
+
+    #[msg("Recipients cannot refer themselves.")]
+    SelfReferral,
+
+    #[msg("The referrer was referred by the recipient.")]
+    ReferralCycle,
+
+    #[msg("The referrer accounts are missing or invalid.")]
+    InvalidReferrer,
+
+    #[msg("The config account is not in a layout that can be migrated.")]
+    UnsupportedConfigLayout,
+
+    #[msg("The rent payer does not match the recipient record.")]
+    InvalidRentPayer,
+
+    #[msg("Transfer-hook mints are not supported in bundles.")]
+    TransferHookNotSupported,
+
+    #[msg("The deposit is too small to mint any pool shares.")]
+    DepositTooSmall,
+
+    #[msg("The recipient record is not in a layout that can be migrated.")]
+    UnsupportedRecordLayout,
+
+    #[msg("The change exceeds the limits the owner set for the operator.")]
+    OperatorLimitExceeded,
+
+    #[msg("Recipient records that take part in referrals cannot be closed.")]
+    ReferralRecordLocked,
+
+    #[msg("The sponsor lock exceeds the maximum, or sponsors still hold shares under the current lock.")]
+    InvalidSponsorLock,
+}
+
+#[error_code]
+pub enum GuardianError {
+    #[msg("This module is paused by the guardian.")]
+    ModulePaused,
+
+    #[msg("Unauthorized action attempted.")]
+    Unauthorized,
+
+    #[msg("The maximum pause duration must be greater than zero.")]
+    InvalidPauseDuration,
 }
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct FaucetDeposited {
+    pub faucet: Pubkey,
+    pub depositor: Pubkey,
+    /// Amount credited to the treasury after any transfer fee.
+    pub amount: u64,
+    pub withheld_fee: u64,
+    pub depositor_total: u64,
+    pub faucet_total: u64,
+}
+
+#[event]
+pub struct FaucetClaimed {
+    pub faucet: Pubkey,
+    pub recipient: Pubkey,
+    /// Amount received by the recipient.
+    pub amount: u64,
+    pub faucet_total: u64,
+}
+
+#[event]
+pub struct FaucetReferral {
+    pub faucet: Pubkey,
+    pub recipient: Pubkey,
+    pub referrer: Pubkey,
+    /// Zero when the referrer is rate-limited or the treasury cannot cover the bonus.
+    pub bonus: u64,
+}
+
+#[event]
+pub struct FeesDistributed {
+    /// Total lamports paid out across all fee recipients.
+    pub amount: u64,
+}
+
+#[event]
+pub struct CreationFeeReferral {
+    pub mint: Pubkey,
+    pub creator: Pubkey,
+    pub referrer: Pubkey,
+    /// Lamports paid to the referrer out of the creation fee.
+    pub amount: u64,
+}
@@ -1,7 +1,13 @@
+pub mod bundle_faucet;
 pub mod factory;
 pub mod faucet;
+pub mod guardian;
+pub mod sol_faucet;
 pub mod token;
 
+pub use bundle_faucet::*;
 pub use factory::*;
 pub use faucet::*;
+pub use guardian::*;
+pub use sol_faucet::*;
 pub use token::*;
@@ -0,0 +1,9 @@
+pub mod add_entry;
+pub mod claim;
+pub mod initialize;
+pub mod withdraw;
+
+pub use add_entry::*;
+pub use claim::*;
+pub use initialize::*;
+pub use withdraw::*;
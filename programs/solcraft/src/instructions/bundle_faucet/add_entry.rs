@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::{
+    BUNDLE_FAUCET_CONFIG_SEEDS, MAX_BUNDLE_ENTRIES, PAUSE_BUNDLE_FAUCET, PROGRAM_STATE_SEEDS,
+};
+use crate::errors::{FaucetError, GuardianError};
+use crate::states::{BundleEntry, BundleFaucetConfig, ProgramState};
+use crate::utils::has_transfer_hook;
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct AddBundleEntry<'info> {
+    #[account(
+      seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+      bump = program_state.bump,
+      constraint = !program_state.is_paused(PAUSE_BUNDLE_FAUCET)? @ GuardianError::ModulePaused,
+   )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+      mut,
+      seeds = [BUNDLE_FAUCET_CONFIG_SEEDS.as_bytes()],
+      bump = bundle_faucet_config.bump,
+      has_one = owner @ FaucetError::Unauthorized,
+   )]
+    pub bundle_faucet_config: Account<'info, BundleFaucetConfig>,
+
+    #[account(
+      constraint = !has_transfer_hook(&mint.to_account_info())? @ FaucetError::TransferHookNotSupported,
+   )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+      init,
+      payer = owner,
+      associated_token::mint = mint,
+      associated_token::authority = bundle_faucet_config,
+      associated_token::token_program = token_program,
+    )]
+    pub treasury_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn add_bundle_entry(ctx: Context<AddBundleEntry>, amount: u64) -> Result<()> {
+    let bundle_faucet_config = &mut ctx.accounts.bundle_faucet_config;
+    let mint = ctx.accounts.mint.key();
+
+    require!(
+        bundle_faucet_config.entries.len() < MAX_BUNDLE_ENTRIES as usize,
+        FaucetError::BundleFull
+    );
+    require!(
+        !bundle_faucet_config
+            .entries
+            .iter()
+            .any(|entry| entry.mint == mint),
+        FaucetError::DuplicateBundleMint
+    );
+
+    bundle_faucet_config.entries.push(BundleEntry {
+        mint,
+        treasury_ata: ctx.accounts.treasury_ata.key(),
+        amount,
+        token_program: ctx.accounts.token_program.key(),
+    });
+
+    Ok(())
+}
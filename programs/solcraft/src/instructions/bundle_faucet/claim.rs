@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::{
+    BUNDLE_FAUCET_CONFIG_SEEDS, BUNDLE_FAUCET_RECIPIENT_SEEDS, DISCRIMINATOR, PAUSE_BUNDLE_FAUCET,
+    PROGRAM_STATE_SEEDS,
+};
+use crate::errors::{FaucetError, GuardianError};
+use crate::states::{BundleFaucetConfig, FaucetRecipientData, ProgramState};
+use crate::utils::{gross_transfer_amount, transfer_checked_with_hooks};
+
+const ACCOUNTS_PER_ENTRY: usize = 4;
+
+/// Remaining accounts must hold one `[mint, treasury_ata, recipient_ata, token_program]` group
+/// per bundle entry, in the same order as `bundle_faucet_config.entries`.
+/// Transfer-hook mints are not supported in bundles.
+#[derive(Accounts)]
+pub struct ClaimBundle<'info> {
+    #[account(
+      seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+      bump = program_state.bump,
+      constraint = !program_state.is_paused(PAUSE_BUNDLE_FAUCET)? @ GuardianError::ModulePaused,
+   )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+      seeds = [BUNDLE_FAUCET_CONFIG_SEEDS.as_bytes()],
+      bump = bundle_faucet_config.bump,
+   )]
+    pub bundle_faucet_config: Account<'info, BundleFaucetConfig>,
+
+    #[account(
+      init_if_needed,
+      payer = recipient,
+      space = DISCRIMINATOR + FaucetRecipientData::INIT_SPACE,
+      seeds = [BUNDLE_FAUCET_RECIPIENT_SEEDS.as_bytes(), recipient.key().as_ref()],
+      bump,
+    )]
+    pub recipient_data: Account<'info, FaucetRecipientData>,
+
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn claim_bundle<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimBundle<'info>>) -> Result<()> {
+    let bundle_faucet_config = &ctx.accounts.bundle_faucet_config;
+    let entries = &bundle_faucet_config.entries;
+    require!(
+        ctx.remaining_accounts.len() == entries.len() * ACCOUNTS_PER_ENTRY,
+        FaucetError::InvalidBundleAccounts
+    );
+
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    require!(
+        ctx.accounts
+            .recipient_data
+            .cooldown_elapsed(bundle_faucet_config.cooldown_seconds, current_timestamp),
+        FaucetError::CooldownNotElapsed
+    );
+
+    let seeds = &[
+        BUNDLE_FAUCET_CONFIG_SEEDS.as_bytes(),
+        &[bundle_faucet_config.bump],
+    ];
+
+    for (entry, accounts) in entries
+        .iter()
+        .zip(ctx.remaining_accounts.chunks(ACCOUNTS_PER_ENTRY))
+    {
+        let mint_info = &accounts[0];
+        let treasury_info = &accounts[1];
+        let recipient_ata_info = &accounts[2];
+        let token_program = Interface::<TokenInterface>::try_from(&accounts[3])?;
+
+        require_keys_eq!(
+            mint_info.key(),
+            entry.mint,
+            FaucetError::InvalidBundleAccounts
+        );
+        require_keys_eq!(
+            treasury_info.key(),
+            entry.treasury_ata,
+            FaucetError::InvalidBundleAccounts
+        );
+        require_keys_eq!(
+            token_program.key(),
+            entry.token_program,
+            FaucetError::InvalidBundleAccounts
+        );
+        let mint = InterfaceAccount::<Mint>::try_from(mint_info)?;
+
+        // Send enough to cover any Token-2022 transfer fee so the recipient receives the full amount.
+        let gross_amount = gross_transfer_amount(mint_info, entry.amount)?;
+        let treasury_ata = InterfaceAccount::<TokenAccount>::try_from(treasury_info)?;
+        require!(
+            treasury_ata.amount >= gross_amount,
+            FaucetError::InsufficientFunds
+        );
+
+        let recipient_ata = InterfaceAccount::<TokenAccount>::try_from(recipient_ata_info)?;
+        require!(
+            recipient_ata.mint == entry.mint && recipient_ata.owner == ctx.accounts.recipient.key(),
+            FaucetError::InvalidBundleAccounts
+        );
+
+        transfer_checked_with_hooks(
+            &token_program.to_account_info(),
+            treasury_info,
+            mint_info,
+            recipient_ata_info,
+            &bundle_faucet_config.to_account_info(),
+            &[],
+            gross_amount,
+            mint.decimals,
+            &[seeds],
+        )?;
+    }
+
+    let recipient_data = &mut ctx.accounts.recipient_data;
+    if recipient_data.last_claimed_at == 0 {
+        recipient_data.initialize(ctx.accounts.recipient.key());
+    }
+    recipient_data.last_claimed_at = current_timestamp;
+
+    Ok(())
+}
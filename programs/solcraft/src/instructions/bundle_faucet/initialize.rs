@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{
+    BUNDLE_FAUCET_CONFIG_SEEDS, DISCRIMINATOR, PAUSE_BUNDLE_FAUCET, PROGRAM_STATE_SEEDS,
+};
+use crate::errors::GuardianError;
+use crate::states::{BundleFaucetConfig, ProgramState};
+
+#[derive(Accounts)]
+pub struct InitializeBundleFaucet<'info> {
+    #[account(
+      seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+      bump = program_state.bump,
+      constraint = !program_state.is_paused(PAUSE_BUNDLE_FAUCET)? @ GuardianError::ModulePaused,
+   )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+      init,
+      payer = owner,
+      space = DISCRIMINATOR + BundleFaucetConfig::INIT_SPACE,
+      seeds = [BUNDLE_FAUCET_CONFIG_SEEDS.as_bytes()],
+      bump,
+   )]
+    pub bundle_faucet_config: Account<'info, BundleFaucetConfig>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_bundle_faucet(ctx: Context<InitializeBundleFaucet>) -> Result<()> {
+    let bundle_faucet_config = &mut ctx.accounts.bundle_faucet_config;
+
+    bundle_faucet_config.owner = ctx.accounts.owner.key();
+    bundle_faucet_config.cooldown_seconds = 3600; // default 1 hour
+    bundle_faucet_config.bump = ctx.bumps.bundle_faucet_config;
+    bundle_faucet_config.entries = Vec::new();
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::{BUNDLE_FAUCET_CONFIG_SEEDS, PAUSE_BUNDLE_FAUCET, PROGRAM_STATE_SEEDS};
+use crate::errors::{FaucetError, GuardianError};
+use crate::states::{BundleFaucetConfig, ProgramState};
+use crate::utils::transfer_checked_with_hooks;
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct WithdrawFromBundle<'info> {
+    #[account(
+      seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+      bump = program_state.bump,
+      constraint = !program_state.is_paused(PAUSE_BUNDLE_FAUCET)? @ GuardianError::ModulePaused,
+   )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+      seeds = [BUNDLE_FAUCET_CONFIG_SEEDS.as_bytes()],
+      bump = bundle_faucet_config.bump,
+      constraint = recipient.key() == bundle_faucet_config.owner @ FaucetError::Unauthorized,
+   )]
+    pub bundle_faucet_config: Account<'info, BundleFaucetConfig>,
+
+    #[account(
+        constraint = bundle_faucet_config.entries.iter().any(|entry| entry.mint == mint.key())
+            @ FaucetError::BundleMintNotFound,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = bundle_faucet_config,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program,
+    )]
+    pub recipient_ata: InterfaceAccount<'info, TokenAccount>,
+
+    pub recipient: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn withdraw_from_bundle<'info>(
+    ctx: Context<'_, '_, 'info, 'info, WithdrawFromBundle<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let treasury_balance = ctx.accounts.treasury_ata.amount;
+    require!(treasury_balance >= amount, FaucetError::InsufficientFunds);
+
+    let seeds = &[
+        BUNDLE_FAUCET_CONFIG_SEEDS.as_bytes(),
+        &[ctx.accounts.bundle_faucet_config.bump],
+    ];
+
+    transfer_checked_with_hooks(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.treasury_ata.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.recipient_ata.to_account_info(),
+        &ctx.accounts.bundle_faucet_config.to_account_info(),
+        ctx.remaining_accounts,
+        amount,
+        ctx.accounts.mint.decimals,
+        &[seeds],
+    )?;
+
+    Ok(())
+}
@@ -1,11 +1,45 @@
+pub mod approve_proposal;
+pub mod cancel_action;
+pub mod configure_timelock;
+pub mod create_proposal;
+pub mod distribute_fees;
+pub mod execute_action;
+pub mod execute_proposal;
+pub mod grant_role;
 pub mod initialize;
+pub mod migrate_config;
 pub mod pause;
+pub mod queue_action;
+pub mod revoke_role;
+pub mod set_admin_signers;
+pub mod set_fee_discount;
+pub mod set_fee_mint;
+pub mod set_fee_recipients;
 pub mod unpause;
 pub mod update_creation_fee;
+pub mod update_fee_schedule;
+pub mod update_referral_fee;
 pub mod withdraw_fees;
 
+pub use approve_proposal::*;
+pub use cancel_action::*;
+pub use configure_timelock::*;
+pub use create_proposal::*;
+pub use distribute_fees::*;
+pub use execute_action::*;
+pub use execute_proposal::*;
+pub use grant_role::*;
 pub use initialize::*;
+pub use migrate_config::*;
 pub use pause::*;
+pub use queue_action::*;
+pub use revoke_role::*;
+pub use set_admin_signers::*;
+pub use set_fee_discount::*;
+pub use set_fee_mint::*;
+pub use set_fee_recipients::*;
 pub use unpause::*;
 pub use update_creation_fee::*;
+pub use update_fee_schedule::*;
+pub use update_referral_fee::*;
 pub use withdraw_fees::*;
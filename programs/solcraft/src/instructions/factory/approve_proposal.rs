@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::{FactoryError, GuardianError};
+use crate::states::{FactoryConfig, ProgramState, Proposal};
+
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PAUSE_FACTORY)? @ GuardianError::ModulePaused,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        seeds = [FACTORY_CONFIG_SEEDS.as_bytes()],
+        bump = factory_config.bump,
+        constraint = factory_config.is_admin_signer(&approver.key()) @ FactoryError::NotAdminSigner,
+    )]
+    pub factory_config: Account<'info, FactoryConfig>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEEDS.as_bytes(), &proposal.id.to_le_bytes()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    pub approver: Signer<'info>,
+}
+
+pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+    let approver = ctx.accounts.approver.key();
+    let proposal = &mut ctx.accounts.proposal;
+    require!(
+        !proposal.approvals.contains(&approver),
+        FactoryError::AlreadyApproved
+    );
+
+    // Drop approvals from removed signers so the list stays within `MAX_ADMIN_SIGNERS`.
+    let factory_config = &ctx.accounts.factory_config;
+    proposal
+        .approvals
+        .retain(|key| factory_config.is_admin_signer(key));
+    proposal.approvals.push(approver);
+
+    Ok(())
+}
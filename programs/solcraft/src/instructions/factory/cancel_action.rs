@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::{FactoryError, GuardianError};
+use crate::states::{FactoryConfig, PendingAction, ProgramState};
+
+#[derive(Accounts)]
+pub struct CancelAdminAction<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PAUSE_FACTORY)? @ GuardianError::ModulePaused,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        seeds = [FACTORY_CONFIG_SEEDS.as_bytes()],
+        bump = factory_config.bump,
+        has_one = admin @ FactoryError::Unauthorized,
+        constraint = !factory_config.is_multisig() @ FactoryError::MultisigRequired,
+    )]
+    pub factory_config: Account<'info, FactoryConfig>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [PENDING_ACTION_SEEDS.as_bytes(), &pending_action.id.to_le_bytes()],
+        bump = pending_action.bump,
+    )]
+    pub pending_action: Account<'info, PendingAction>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+pub fn cancel_admin_action(_ctx: Context<CancelAdminAction>) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::{FactoryError, GuardianError};
+use crate::states::{FactoryConfig, ProgramState};
+
+#[derive(Accounts)]
+#[instruction(delay_seconds: u64, withdrawal_threshold: u64)]
+pub struct ConfigureTimelock<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PAUSE_FACTORY)? @ GuardianError::ModulePaused,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
+        seeds = [FACTORY_CONFIG_SEEDS.as_bytes()],
+        bump = factory_config.bump,
+        has_one = admin @ FactoryError::Unauthorized,
+        constraint = !factory_config.is_multisig() @ FactoryError::MultisigRequired,
+        constraint = !factory_config.is_timelocked() @ FactoryError::TimelockRequired,
+    )]
+    pub factory_config: Account<'info, FactoryConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+/// Enables the timelock. Once enabled, it can only be changed through a queued action.
+pub fn configure_timelock(
+    ctx: Context<ConfigureTimelock>,
+    delay_seconds: u64,
+    withdrawal_threshold: u64,
+) -> Result<()> {
+    let factory_config = &mut ctx.accounts.factory_config;
+    factory_config.timelock_delay_seconds = delay_seconds;
+    factory_config.timelock_withdrawal_threshold = withdrawal_threshold;
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::{FactoryError, GuardianError};
+use crate::states::{FactoryConfig, ProgramState, Proposal, ProposalAction};
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PAUSE_FACTORY)? @ GuardianError::ModulePaused,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
+        seeds = [FACTORY_CONFIG_SEEDS.as_bytes()],
+        bump = factory_config.bump,
+        constraint = factory_config.is_admin_signer(&proposer.key()) @ FactoryError::NotAdminSigner,
+    )]
+    pub factory_config: Account<'info, FactoryConfig>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = DISCRIMINATOR + Proposal::INIT_SPACE,
+        seeds = [PROPOSAL_SEEDS.as_bytes(), &factory_config.next_proposal_id.to_le_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// The proposer's approval is recorded immediately.
+pub fn create_proposal(ctx: Context<CreateProposal>, action: ProposalAction) -> Result<()> {
+    let factory_config = &mut ctx.accounts.factory_config;
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.id = factory_config.next_proposal_id;
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.action = action;
+    proposal.created_at = Clock::get()?.unix_timestamp;
+    proposal.approvals = vec![ctx.accounts.proposer.key()];
+    proposal.bump = ctx.bumps.proposal;
+
+    factory_config.next_proposal_id += 1;
+
+    Ok(())
+}
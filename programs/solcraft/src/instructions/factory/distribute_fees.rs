@@ -0,0 +1,113 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::constants::*;
+use crate::errors::{FactoryError, GuardianError};
+use crate::events::FeesDistributed;
+use crate::states::{FactoryConfig, ProgramState};
+
+/// Permissionless. Remaining accounts must be the configured fee recipient wallets,
+/// writable and in the same order as `factory_config.fee_recipients`.
+#[derive(Accounts)]
+pub struct DistributeFees<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PAUSE_FACTORY)? @ GuardianError::ModulePaused,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        seeds = [FACTORY_CONFIG_SEEDS.as_bytes()],
+        bump = factory_config.bump,
+    )]
+    pub factory_config: Account<'info, FactoryConfig>,
+
+    #[account(
+        mut,
+        seeds = [FACTORY_TREASURY.as_bytes()],
+        bump = factory_config.treasury_bump,
+        address = factory_config.treasury_account,
+    )]
+    pub treasury_account: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn distribute_fees<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DistributeFees<'info>>,
+) -> Result<()> {
+    let fee_recipients = &ctx.accounts.factory_config.fee_recipients;
+    require!(!fee_recipients.is_empty(), FactoryError::NoFeeRecipients);
+    require!(
+        ctx.remaining_accounts.len() == fee_recipients.len(),
+        FactoryError::InvalidFeeRecipientAccounts
+    );
+
+    let treasury_info = ctx.accounts.treasury_account.to_account_info();
+
+    // Keep the treasury account rent-exempt, as in `withdraw_fees`.
+    let rent = Rent::get()?;
+    let rent_exempt_minimum = rent.minimum_balance(treasury_info.data_len());
+    let distributable_amount = treasury_info.lamports().saturating_sub(rent_exempt_minimum);
+
+    if distributable_amount == 0 {
+        return Ok(());
+    }
+
+    let seeds = &[
+        FACTORY_TREASURY.as_bytes(),
+        &[ctx.accounts.factory_config.treasury_bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let mut remaining_amount = distributable_amount;
+    let mut distributed_amount = 0u64;
+    let last_index = fee_recipients.len() - 1;
+    for (index, (fee_recipient, destination)) in fee_recipients
+        .iter()
+        .zip(ctx.remaining_accounts)
+        .enumerate()
+    {
+        require_keys_eq!(
+            destination.key(),
+            fee_recipient.recipient,
+            FactoryError::InvalidFeeRecipientAccounts
+        );
+
+        // The last recipient also receives any rounding dust.
+        let share = if index == last_index {
+            remaining_amount
+        } else {
+            (distributable_amount as u128 * fee_recipient.bps as u128 / MAX_BPS as u128) as u64
+        };
+        remaining_amount -= share;
+
+        // A share that would leave an unfunded recipient below rent exemption would fail the
+        // whole distribution, so it stays in the treasury instead.
+        let recipient_stays_rent_exempt = destination.lamports().saturating_add(share)
+            >= rent.minimum_balance(destination.data_len());
+
+        if share == 0 || !recipient_stays_rent_exempt {
+            continue;
+        }
+
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: treasury_info.clone(),
+                    to: destination.clone(),
+                },
+                signer,
+            ),
+            share,
+        )?;
+        distributed_amount += share;
+    }
+
+    emit!(FeesDistributed {
+        amount: distributed_amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,179 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenInterface;
+
+use crate::constants::*;
+use crate::errors::{FactoryError, GuardianError};
+use crate::instructions::factory::set_fee_recipients::validate_fee_recipients;
+use crate::instructions::factory::withdraw_fees::{
+    sweep_token_fees, transfer_from_treasury, treasury_withdraw_amount,
+};
+use crate::states::{AdminAction, FactoryConfig, FeeMintConfig, PendingAction, ProgramState};
+
+/// `SweepTokenFees` takes `token_program` and the same `[fee_mint, treasury_ata, destination_ata]`
+/// remaining account triples as `withdraw_fees`.
+#[derive(Accounts)]
+pub struct ExecuteAdminAction<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PAUSE_FACTORY)? @ GuardianError::ModulePaused,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
+        seeds = [FACTORY_CONFIG_SEEDS.as_bytes()],
+        bump = factory_config.bump,
+        has_one = admin @ FactoryError::Unauthorized,
+        constraint = !factory_config.is_multisig() @ FactoryError::MultisigRequired,
+    )]
+    pub factory_config: Account<'info, FactoryConfig>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [PENDING_ACTION_SEEDS.as_bytes(), &pending_action.id.to_le_bytes()],
+        bump = pending_action.bump,
+    )]
+    pub pending_action: Account<'info, PendingAction>,
+
+    #[account(
+        mut,
+        seeds = [FACTORY_TREASURY.as_bytes()],
+        bump = factory_config.treasury_bump,
+        address = factory_config.treasury_account,
+    )]
+    pub treasury_account: SystemAccount<'info>,
+
+    /// CHECK: Only required for fee withdrawals; must match the queued destination.
+    #[account(mut)]
+    pub destination: Option<UncheckedAccount<'info>>,
+
+    // Only required for `SetFeeMint`; must be the config of the queued mint.
+    #[account(
+        mut,
+        seeds = [FEE_MINT_CONFIG_SEEDS.as_bytes(), fee_mint_config.mint.as_ref()],
+        bump = fee_mint_config.bump,
+    )]
+    pub fee_mint_config: Option<Account<'info, FeeMintConfig>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub fn execute_admin_action<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExecuteAdminAction<'info>>,
+) -> Result<()> {
+    let pending_action = &ctx.accounts.pending_action;
+    let factory_config = &mut ctx.accounts.factory_config;
+
+    // A delay raised after queueing also applies to actions that are already queued.
+    let ready_at = pending_action.eta.max(
+        pending_action
+            .queued_at
+            .saturating_add(factory_config.timelock_delay_seconds as i64),
+    );
+    require!(
+        Clock::get()?.unix_timestamp >= ready_at,
+        FactoryError::TimelockNotElapsed
+    );
+
+    match pending_action.action.clone() {
+        AdminAction::UpdateCreationFee {
+            creation_fee_lamports,
+        } => {
+            factory_config.creation_fee_lamports = creation_fee_lamports;
+        }
+        AdminAction::UpdateFeeSchedule {
+            token_2022_surcharge_lamports,
+            volume_discount_threshold,
+            volume_discount_bps,
+        } => {
+            require!(
+                volume_discount_bps <= MAX_BPS,
+                FactoryError::InvalidBasisPoints
+            );
+            factory_config.token_2022_surcharge_lamports = token_2022_surcharge_lamports;
+            factory_config.volume_discount_threshold = volume_discount_threshold;
+            factory_config.volume_discount_bps = volume_discount_bps;
+        }
+        AdminAction::SetAdmin { new_admin } => {
+            factory_config.admin = new_admin;
+        }
+        AdminAction::WithdrawFees {
+            amount,
+            destination,
+        } => {
+            let Some(destination_account) = &ctx.accounts.destination else {
+                return err!(FactoryError::InvalidActionAccounts);
+            };
+            require_keys_eq!(
+                destination_account.key(),
+                destination,
+                FactoryError::InvalidActionAccounts
+            );
+
+            let treasury_info = ctx.accounts.treasury_account.to_account_info();
+            let amount = treasury_withdraw_amount(&treasury_info, Some(amount))?;
+            transfer_from_treasury(
+                factory_config,
+                &treasury_info,
+                &destination_account.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                amount,
+            )?;
+        }
+        AdminAction::ConfigureTimelock {
+            delay_seconds,
+            withdrawal_threshold,
+        } => {
+            factory_config.timelock_delay_seconds = delay_seconds;
+            factory_config.timelock_withdrawal_threshold = withdrawal_threshold;
+        }
+        AdminAction::SweepTokenFees { destination } => {
+            let Some(destination_account) = &ctx.accounts.destination else {
+                return err!(FactoryError::InvalidActionAccounts);
+            };
+            require_keys_eq!(
+                destination_account.key(),
+                destination,
+                FactoryError::InvalidActionAccounts
+            );
+
+            sweep_token_fees(
+                factory_config,
+                &ctx.accounts.treasury_account.to_account_info(),
+                &destination,
+                ctx.accounts.token_program.as_ref(),
+                ctx.remaining_accounts,
+            )?;
+        }
+        AdminAction::SetFeeMint {
+            mint,
+            price,
+            enabled,
+        } => {
+            let Some(fee_mint_config) = &mut ctx.accounts.fee_mint_config else {
+                return err!(FactoryError::InvalidActionAccounts);
+            };
+            require_keys_eq!(
+                fee_mint_config.mint,
+                mint,
+                FactoryError::InvalidActionAccounts
+            );
+
+            fee_mint_config.price = price;
+            fee_mint_config.enabled = enabled;
+        }
+        AdminAction::SetFeeRecipients { recipients } => {
+            validate_fee_recipients(&recipients)?;
+            factory_config.fee_recipients = recipients;
+        }
+    }
+
+    Ok(())
+}
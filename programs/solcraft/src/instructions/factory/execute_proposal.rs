@@ -0,0 +1,311 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenInterface;
+
+use crate::constants::*;
+use crate::errors::{FactoryError, GuardianError};
+use crate::instructions::factory::set_admin_signers::validate_admin_signers;
+use crate::instructions::factory::set_fee_recipients::validate_fee_recipients;
+use crate::instructions::factory::withdraw_fees::{
+    sweep_token_fees, transfer_from_treasury, treasury_withdraw_amount,
+};
+use crate::states::{
+    FactoryConfig, FactoryRole, FeeDiscount, FeeMintConfig, ProgramState, Proposal, ProposalAction,
+};
+use crate::utils::create_pda_account;
+
+/// Proposals that create or close a fee discount, role grant or pending action pass that PDA
+/// as `target`; the executor pays for new accounts and receives the rent of closed ones.
+/// `SweepTokenFees` takes `token_program` and the same `[fee_mint, treasury_ata, destination_ata]`
+/// remaining account triples as `withdraw_fees`.
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PAUSE_FACTORY)? @ GuardianError::ModulePaused,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
+        seeds = [FACTORY_CONFIG_SEEDS.as_bytes()],
+        bump = factory_config.bump,
+        constraint = factory_config.is_admin_signer(&executor.key()) @ FactoryError::NotAdminSigner,
+    )]
+    pub factory_config: Account<'info, FactoryConfig>,
+
+    #[account(
+        mut,
+        close = proposer,
+        has_one = proposer,
+        seeds = [PROPOSAL_SEEDS.as_bytes(), &proposal.id.to_le_bytes()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    // Receives the proposal account's rent back.
+    #[account(mut)]
+    pub proposer: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [FACTORY_TREASURY.as_bytes()],
+        bump = factory_config.treasury_bump,
+        address = factory_config.treasury_account,
+    )]
+    pub treasury_account: SystemAccount<'info>,
+
+    /// CHECK: Only required for fee withdrawals; must match the proposed destination.
+    #[account(mut)]
+    pub destination: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Only required for proposals that create or close a PDA; must be the address
+    /// derived from the proposed action.
+    #[account(mut)]
+    pub target: Option<UncheckedAccount<'info>>,
+
+    // Only required for `SetFeeMint`; must be the config of the proposed mint.
+    #[account(
+        mut,
+        seeds = [FEE_MINT_CONFIG_SEEDS.as_bytes(), fee_mint_config.mint.as_ref()],
+        bump = fee_mint_config.bump,
+    )]
+    pub fee_mint_config: Option<Account<'info, FeeMintConfig>>,
+
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub fn execute_proposal<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExecuteProposal<'info>>,
+) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+    let factory_config = &mut ctx.accounts.factory_config;
+    require!(
+        proposal.approval_count(factory_config) >= factory_config.admin_threshold as usize,
+        FactoryError::ThresholdNotMet
+    );
+
+    // Pausing stays immediate so the signers can react to an incident.
+    if !matches!(
+        proposal.action,
+        ProposalAction::Pause | ProposalAction::Unpause
+    ) {
+        require!(
+            Clock::get()?.unix_timestamp
+                >= proposal
+                    .created_at
+                    .saturating_add(factory_config.timelock_delay_seconds as i64),
+            FactoryError::TimelockNotElapsed
+        );
+    }
+
+    let executor = ctx.accounts.executor.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+
+    match proposal.action.clone() {
+        ProposalAction::Pause => {
+            factory_config.paused = true;
+        }
+        ProposalAction::Unpause => {
+            factory_config.paused = false;
+        }
+        ProposalAction::UpdateCreationFee {
+            creation_fee_lamports,
+        } => {
+            factory_config.creation_fee_lamports = creation_fee_lamports;
+        }
+        ProposalAction::UpdateFeeSchedule {
+            token_2022_surcharge_lamports,
+            volume_discount_threshold,
+            volume_discount_bps,
+        } => {
+            require!(
+                volume_discount_bps <= MAX_BPS,
+                FactoryError::InvalidBasisPoints
+            );
+            factory_config.token_2022_surcharge_lamports = token_2022_surcharge_lamports;
+            factory_config.volume_discount_threshold = volume_discount_threshold;
+            factory_config.volume_discount_bps = volume_discount_bps;
+        }
+        ProposalAction::WithdrawFees {
+            amount,
+            destination,
+        } => {
+            let Some(destination_account) = &ctx.accounts.destination else {
+                return err!(FactoryError::InvalidActionAccounts);
+            };
+            require_keys_eq!(
+                destination_account.key(),
+                destination,
+                FactoryError::InvalidActionAccounts
+            );
+
+            let treasury_info = ctx.accounts.treasury_account.to_account_info();
+            let amount = treasury_withdraw_amount(&treasury_info, Some(amount))?;
+            transfer_from_treasury(
+                factory_config,
+                &treasury_info,
+                &destination_account.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                amount,
+            )?;
+        }
+        ProposalAction::SetAdminSigners { signers, threshold } => {
+            validate_admin_signers(&signers, threshold)?;
+            factory_config.admin_signers = signers;
+            factory_config.admin_threshold = threshold;
+        }
+        ProposalAction::SetFeeRecipients { recipients } => {
+            validate_fee_recipients(&recipients)?;
+            factory_config.fee_recipients = recipients;
+        }
+        ProposalAction::UpdateReferralFee { referral_fee_bps } => {
+            require!(
+                referral_fee_bps <= MAX_BPS,
+                FactoryError::InvalidBasisPoints
+            );
+            factory_config.referral_fee_bps = referral_fee_bps;
+        }
+        ProposalAction::ConfigureTimelock {
+            delay_seconds,
+            withdrawal_threshold,
+        } => {
+            factory_config.timelock_delay_seconds = delay_seconds;
+            factory_config.timelock_withdrawal_threshold = withdrawal_threshold;
+        }
+        ProposalAction::SetFeeMint {
+            mint,
+            price,
+            enabled,
+        } => {
+            let Some(fee_mint_config) = &mut ctx.accounts.fee_mint_config else {
+                return err!(FactoryError::InvalidActionAccounts);
+            };
+            require_keys_eq!(
+                fee_mint_config.mint,
+                mint,
+                FactoryError::InvalidActionAccounts
+            );
+
+            fee_mint_config.price = price;
+            fee_mint_config.enabled = enabled;
+        }
+        ProposalAction::SetFeeDiscount {
+            wallet,
+            discount_bps,
+        } => {
+            require!(discount_bps <= MAX_BPS, FactoryError::InvalidBasisPoints);
+
+            let seeds: &[&[u8]] = &[FEE_DISCOUNT_SEEDS.as_bytes(), wallet.as_ref()];
+            let (target, bump) = proposal_target(&ctx.accounts.target, seeds)?;
+            if target.data_is_empty() {
+                create_pda_account(
+                    &target,
+                    &executor,
+                    &system_program,
+                    DISCRIMINATOR + FeeDiscount::INIT_SPACE,
+                    &[seeds[0], seeds[1], &[bump]],
+                )?;
+            }
+
+            let fee_discount = FeeDiscount {
+                wallet,
+                discount_bps,
+                bump,
+            };
+            fee_discount.try_serialize(&mut &mut target.try_borrow_mut_data()?[..])?;
+        }
+        ProposalAction::RemoveFeeDiscount { wallet } => {
+            let seeds: &[&[u8]] = &[FEE_DISCOUNT_SEEDS.as_bytes(), wallet.as_ref()];
+            let (target, _) = proposal_target(&ctx.accounts.target, seeds)?;
+            close_target(&target, &executor)?;
+        }
+        ProposalAction::GrantRole { holder, role } => {
+            let seeds: &[&[u8]] = &[
+                FACTORY_ROLE_SEEDS.as_bytes(),
+                holder.as_ref(),
+                &[role as u8],
+            ];
+            let (target, bump) = proposal_target(&ctx.accounts.target, seeds)?;
+            create_pda_account(
+                &target,
+                &executor,
+                &system_program,
+                DISCRIMINATOR + FactoryRole::INIT_SPACE,
+                &[seeds[0], seeds[1], seeds[2], &[bump]],
+            )?;
+
+            let factory_role = FactoryRole { holder, role, bump };
+            factory_role.try_serialize(&mut &mut target.try_borrow_mut_data()?[..])?;
+        }
+        ProposalAction::RevokeRole { holder, role } => {
+            let seeds: &[&[u8]] = &[
+                FACTORY_ROLE_SEEDS.as_bytes(),
+                holder.as_ref(),
+                &[role as u8],
+            ];
+            let (target, _) = proposal_target(&ctx.accounts.target, seeds)?;
+            close_target(&target, &executor)?;
+        }
+        ProposalAction::CancelAdminAction { action_id } => {
+            let seeds: &[&[u8]] = &[PENDING_ACTION_SEEDS.as_bytes(), &action_id.to_le_bytes()];
+            let (target, _) = proposal_target(&ctx.accounts.target, seeds)?;
+            close_target(&target, &executor)?;
+        }
+        ProposalAction::SweepTokenFees { destination } => {
+            let Some(destination_account) = &ctx.accounts.destination else {
+                return err!(FactoryError::InvalidActionAccounts);
+            };
+            require_keys_eq!(
+                destination_account.key(),
+                destination,
+                FactoryError::InvalidActionAccounts
+            );
+
+            sweep_token_fees(
+                factory_config,
+                &ctx.accounts.treasury_account.to_account_info(),
+                &destination,
+                ctx.accounts.token_program.as_ref(),
+                ctx.remaining_accounts,
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns `target` and its bump once it matches the program address derived from `seeds`.
+fn proposal_target<'info>(
+    target: &Option<UncheckedAccount<'info>>,
+    seeds: &[&[u8]],
+) -> Result<(AccountInfo<'info>, u8)> {
+    let Some(target) = target else {
+        return err!(FactoryError::InvalidActionAccounts);
+    };
+    let (address, bump) = Pubkey::find_program_address(seeds, &crate::ID);
+    require_keys_eq!(target.key(), address, FactoryError::InvalidActionAccounts);
+
+    Ok((target.to_account_info(), bump))
+}
+
+/// Closes a program-owned `target` as Anchor's `close` does, refunding its rent to `executor`.
+fn close_target<'info>(target: &AccountInfo<'info>, executor: &AccountInfo<'info>) -> Result<()> {
+    require_keys_eq!(
+        *target.owner,
+        crate::ID,
+        FactoryError::InvalidActionAccounts
+    );
+
+    executor.add_lamports(target.lamports())?;
+    target.sub_lamports(target.lamports())?;
+    target.assign(&System::id());
+    target.resize(0)?;
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::{FactoryError, GuardianError};
+use crate::states::{FactoryConfig, FactoryRole, ProgramState, Role};
+
+#[derive(Accounts)]
+#[instruction(role: Role)]
+pub struct GrantRole<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PAUSE_FACTORY)? @ GuardianError::ModulePaused,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        seeds = [FACTORY_CONFIG_SEEDS.as_bytes()],
+        bump = factory_config.bump,
+        constraint = factory_config.has_role(&authority.key(), authority_role.as_deref(), Role::SuperAdmin) @ FactoryError::Unauthorized,
+        constraint = !factory_config.is_multisig() @ FactoryError::MultisigRequired,
+    )]
+    pub factory_config: Account<'info, FactoryConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = DISCRIMINATOR + FactoryRole::INIT_SPACE,
+        seeds = [FACTORY_ROLE_SEEDS.as_bytes(), holder.key().as_ref(), &[role as u8]],
+        bump
+    )]
+    pub factory_role: Account<'info, FactoryRole>,
+
+    /// CHECK: Wallet receiving the role; only its key is used.
+    pub holder: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [FACTORY_ROLE_SEEDS.as_bytes(), authority_role.holder.as_ref(), &[authority_role.role as u8]],
+        bump = authority_role.bump,
+    )]
+    pub authority_role: Option<Account<'info, FactoryRole>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn grant_role(ctx: Context<GrantRole>, role: Role) -> Result<()> {
+    let factory_role = &mut ctx.accounts.factory_role;
+    factory_role.holder = ctx.accounts.holder.key();
+    factory_role.role = role;
+    factory_role.bump = ctx.bumps.factory_role;
+
+    Ok(())
+}
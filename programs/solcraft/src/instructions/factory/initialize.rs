@@ -1,11 +1,19 @@
 use anchor_lang::{prelude::*, system_program};
 
 use crate::constants::*;
-use crate::states::FactoryConfig;
+use crate::errors::GuardianError;
+use crate::states::{FactoryConfig, ProgramState};
 
 #[derive(Accounts)]
 #[instruction(creation_fee_lamports: u64)]
 pub struct InitializeFactory<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PAUSE_FACTORY)? @ GuardianError::ModulePaused,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
     #[account(
         init,
         payer = admin,
@@ -38,12 +46,27 @@ pub fn initialize_factory(
     creation_fee_lamports: u64,
 ) -> Result<()> {
     let factory_config = &mut ctx.accounts.factory_config;
+    factory_config.version = FACTORY_CONFIG_VERSION;
     factory_config.creation_fee_lamports = creation_fee_lamports;
     factory_config.admin = ctx.accounts.admin.key();
     factory_config.bump = ctx.bumps.factory_config;
     factory_config.paused = false;
     factory_config.treasury_account = ctx.accounts.treasury_account.key();
     factory_config.treasury_bump = ctx.bumps.treasury_account;
+    factory_config.token_2022_surcharge_lamports = 0;
+    factory_config.volume_discount_threshold = 0;
+    factory_config.volume_discount_bps = 0;
+    factory_config.referral_fee_bps = 0;
+    factory_config.timelock_delay_seconds = 0;
+    factory_config.timelock_withdrawal_threshold = 0;
+    factory_config.withdrawal_window_start = 0;
+    factory_config.withdrawn_in_window = 0;
+    factory_config.next_action_id = 0;
+    factory_config.admin_threshold = 0;
+    factory_config.next_proposal_id = 0;
+    factory_config.admin_signers = Vec::new();
+    factory_config.fee_recipients = Vec::new();
+    factory_config.reserved = [0; CONFIG_RESERVED_BYTES];
 
     Ok(())
 }
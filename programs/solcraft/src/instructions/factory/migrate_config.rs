@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::{FactoryError, GuardianError};
+use crate::states::{FactoryConfig, LegacyFactoryConfig, ProgramState};
+use crate::utils::realloc_rent_exempt;
+
+#[derive(Accounts)]
+pub struct MigrateFactoryConfig<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PAUSE_FACTORY)? @ GuardianError::ModulePaused,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    /// CHECK: Deserialized by hand because the legacy layout no longer matches `FactoryConfig`.
+    #[account(
+        mut,
+        seeds = [FACTORY_CONFIG_SEEDS.as_bytes()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub factory_config: UncheckedAccount<'info>,
+
+    // Pays for the extra rent of the larger account.
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Upgrades a legacy `FactoryConfig` in place to the current layout, keeping its settings
+/// and defaulting every newer field as `initialize_factory` does.
+pub fn migrate_factory_config(ctx: Context<MigrateFactoryConfig>) -> Result<()> {
+    let factory_config_info = ctx.accounts.factory_config.to_account_info();
+    let legacy = {
+        let data = factory_config_info.try_borrow_data()?;
+        require!(
+            data.len() == LegacyFactoryConfig::LEN
+                && data.starts_with(FactoryConfig::DISCRIMINATOR),
+            FactoryError::UnsupportedConfigLayout
+        );
+        LegacyFactoryConfig::deserialize(&mut &data[DISCRIMINATOR..])?
+    };
+    require_keys_eq!(
+        legacy.admin,
+        ctx.accounts.admin.key(),
+        FactoryError::Unauthorized
+    );
+
+    let factory_config = FactoryConfig {
+        version: FACTORY_CONFIG_VERSION,
+        admin: legacy.admin,
+        paused: legacy.paused,
+        treasury_account: legacy.treasury_account,
+        bump: legacy.bump,
+        treasury_bump: legacy.treasury_bump,
+        creation_fee_lamports: legacy.creation_fee_lamports,
+        token_2022_surcharge_lamports: 0,
+        volume_discount_threshold: 0,
+        volume_discount_bps: 0,
+        referral_fee_bps: 0,
+        timelock_delay_seconds: 0,
+        timelock_withdrawal_threshold: 0,
+        withdrawal_window_start: 0,
+        withdrawn_in_window: 0,
+        next_action_id: 0,
+        admin_threshold: 0,
+        next_proposal_id: 0,
+        admin_signers: Vec::new(),
+        fee_recipients: Vec::new(),
+        reserved: [0; CONFIG_RESERVED_BYTES],
+    };
+
+    realloc_rent_exempt(
+        &factory_config_info,
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        DISCRIMINATOR + FactoryConfig::INIT_SPACE,
+    )?;
+
+    let mut data = factory_config_info.try_borrow_mut_data()?;
+    factory_config.try_serialize(&mut &mut data[..])?;
+
+    Ok(())
+}
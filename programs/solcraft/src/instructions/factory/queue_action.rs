@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::{FactoryError, GuardianError};
+use crate::states::{AdminAction, FactoryConfig, PendingAction, ProgramState};
+
+#[derive(Accounts)]
+pub struct QueueAdminAction<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PAUSE_FACTORY)? @ GuardianError::ModulePaused,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
+        seeds = [FACTORY_CONFIG_SEEDS.as_bytes()],
+        bump = factory_config.bump,
+        has_one = admin @ FactoryError::Unauthorized,
+        constraint = !factory_config.is_multisig() @ FactoryError::MultisigRequired,
+        constraint = factory_config.is_timelocked() @ FactoryError::TimelockNotEnabled,
+    )]
+    pub factory_config: Account<'info, FactoryConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = DISCRIMINATOR + PendingAction::INIT_SPACE,
+        seeds = [PENDING_ACTION_SEEDS.as_bytes(), &factory_config.next_action_id.to_le_bytes()],
+        bump
+    )]
+    pub pending_action: Account<'info, PendingAction>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn queue_admin_action(ctx: Context<QueueAdminAction>, action: AdminAction) -> Result<()> {
+    let factory_config = &mut ctx.accounts.factory_config;
+    let queued_at = Clock::get()?.unix_timestamp;
+
+    let pending_action = &mut ctx.accounts.pending_action;
+    pending_action.id = factory_config.next_action_id;
+    pending_action.action = action;
+    pending_action.queued_at = queued_at;
+    pending_action.eta = queued_at.saturating_add(factory_config.timelock_delay_seconds as i64);
+    pending_action.bump = ctx.bumps.pending_action;
+
+    factory_config.next_action_id += 1;
+
+    Ok(())
+}
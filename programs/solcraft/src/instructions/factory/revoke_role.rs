@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::{FactoryError, GuardianError};
+use crate::states::{FactoryConfig, FactoryRole, ProgramState, Role};
+
+#[derive(Accounts)]
+pub struct RevokeRole<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PAUSE_FACTORY)? @ GuardianError::ModulePaused,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        seeds = [FACTORY_CONFIG_SEEDS.as_bytes()],
+        bump = factory_config.bump,
+        constraint = factory_config.has_role(&authority.key(), authority_role.as_deref(), Role::SuperAdmin) @ FactoryError::Unauthorized,
+        constraint = !factory_config.is_multisig() @ FactoryError::MultisigRequired,
+    )]
+    pub factory_config: Account<'info, FactoryConfig>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [FACTORY_ROLE_SEEDS.as_bytes(), factory_role.holder.as_ref(), &[factory_role.role as u8]],
+        bump = factory_role.bump,
+    )]
+    pub factory_role: Account<'info, FactoryRole>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [FACTORY_ROLE_SEEDS.as_bytes(), authority_role.holder.as_ref(), &[authority_role.role as u8]],
+        bump = authority_role.bump,
+    )]
+    pub authority_role: Option<Account<'info, FactoryRole>>,
+}
+
+pub fn revoke_role(_ctx: Context<RevokeRole>) -> Result<()> {
+    Ok(())
+}
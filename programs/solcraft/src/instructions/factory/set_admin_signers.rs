@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::{FactoryError, GuardianError};
+use crate::states::{FactoryConfig, ProgramState};
+
+#[derive(Accounts)]
+#[instruction(signers: Vec<Pubkey>, threshold: u8)]
+pub struct SetAdminSigners<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PAUSE_FACTORY)? @ GuardianError::ModulePaused,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
+        seeds = [FACTORY_CONFIG_SEEDS.as_bytes()],
+        bump = factory_config.bump,
+        has_one = admin @ FactoryError::Unauthorized,
+        constraint = !factory_config.is_multisig() @ FactoryError::MultisigRequired,
+    )]
+    pub factory_config: Account<'info, FactoryConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+/// Hands admin control to an M-of-N signer set. Once set, the admin set can only be
+/// changed through a proposal; an empty set returns control to `admin`.
+pub fn set_admin_signers(
+    ctx: Context<SetAdminSigners>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    validate_admin_signers(&signers, threshold)?;
+
+    let factory_config = &mut ctx.accounts.factory_config;
+    factory_config.admin_signers = signers;
+    factory_config.admin_threshold = threshold;
+
+    Ok(())
+}
+
+pub(crate) fn validate_admin_signers(signers: &[Pubkey], threshold: u8) -> Result<()> {
+    require!(
+        signers.len() <= MAX_ADMIN_SIGNERS as usize,
+        FactoryError::InvalidAdminSigners
+    );
+
+    if signers.is_empty() {
+        require!(threshold == 0, FactoryError::InvalidAdminSigners);
+        return Ok(());
+    }
+
+    require!(
+        threshold >= 1 && threshold as usize <= signers.len(),
+        FactoryError::InvalidAdminSigners
+    );
+    for (index, signer) in signers.iter().enumerate() {
+        require!(
+            !signers[..index].contains(signer),
+            FactoryError::InvalidAdminSigners
+        );
+    }
+
+    Ok(())
+}
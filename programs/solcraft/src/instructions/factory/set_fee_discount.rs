@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::{FactoryError, GuardianError};
+use crate::states::{FactoryConfig, FeeDiscount, ProgramState};
+
+#[derive(Accounts)]
+#[instruction(discount_bps: u16)]
+pub struct SetFeeDiscount<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PAUSE_FACTORY)? @ GuardianError::ModulePaused,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        seeds = [FACTORY_CONFIG_SEEDS.as_bytes()],
+        bump = factory_config.bump,
+        has_one = admin @ FactoryError::Unauthorized,
+        constraint = !factory_config.is_multisig() @ FactoryError::MultisigRequired,
+    )]
+    pub factory_config: Account<'info, FactoryConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = DISCRIMINATOR + FeeDiscount::INIT_SPACE,
+        seeds = [FEE_DISCOUNT_SEEDS.as_bytes(), wallet.key().as_ref()],
+        bump
+    )]
+    pub fee_discount: Account<'info, FeeDiscount>,
+
+    /// CHECK: Partner wallet receiving the discount; only its key is used.
+    pub wallet: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveFeeDiscount<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PAUSE_FACTORY)? @ GuardianError::ModulePaused,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        seeds = [FACTORY_CONFIG_SEEDS.as_bytes()],
+        bump = factory_config.bump,
+        has_one = admin @ FactoryError::Unauthorized,
+        constraint = !factory_config.is_multisig() @ FactoryError::MultisigRequired,
+    )]
+    pub factory_config: Account<'info, FactoryConfig>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [FEE_DISCOUNT_SEEDS.as_bytes(), fee_discount.wallet.as_ref()],
+        bump = fee_discount.bump,
+    )]
+    pub fee_discount: Account<'info, FeeDiscount>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+pub fn set_fee_discount(ctx: Context<SetFeeDiscount>, discount_bps: u16) -> Result<()> {
+    require!(discount_bps <= MAX_BPS, FactoryError::InvalidBasisPoints);
+
+    let fee_discount = &mut ctx.accounts.fee_discount;
+    fee_discount.wallet = ctx.accounts.wallet.key();
+    fee_discount.discount_bps = discount_bps;
+    fee_discount.bump = ctx.bumps.fee_discount;
+
+    Ok(())
+}
+
+pub fn remove_fee_discount(_ctx: Context<RemoveFeeDiscount>) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::*;
+use crate::errors::{FactoryError, GuardianError};
+use crate::states::{FactoryConfig, FeeMintConfig, ProgramState};
+
+#[derive(Accounts)]
+#[instruction(price: u64, enabled: bool)]
+pub struct SetFeeMint<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PAUSE_FACTORY)? @ GuardianError::ModulePaused,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        seeds = [FACTORY_CONFIG_SEEDS.as_bytes()],
+        bump = factory_config.bump,
+        has_one = admin @ FactoryError::Unauthorized,
+    )]
+    pub factory_config: Account<'info, FactoryConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = DISCRIMINATOR + FeeMintConfig::INIT_SPACE,
+        seeds = [FEE_MINT_CONFIG_SEEDS.as_bytes(), fee_mint.key().as_ref()],
+        bump
+    )]
+    pub fee_mint_config: Account<'info, FeeMintConfig>,
+
+    #[account(mint::token_program = token_program)]
+    pub fee_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [FACTORY_TREASURY.as_bytes()],
+        bump = factory_config.treasury_bump,
+        address = factory_config.treasury_account,
+    )]
+    pub treasury_account: SystemAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = fee_mint,
+        associated_token::authority = treasury_account,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Adds or updates an accepted creation fee mint; `enabled = false` stops accepting it.
+/// While the timelock is enabled, existing configs can only change through a queued
+/// `AdminAction::SetFeeMint`. Under multisig the admin can only register a new, disabled
+/// config, which a `ProposalAction::SetFeeMint` then prices and enables.
+pub fn set_fee_mint(ctx: Context<SetFeeMint>, price: u64, enabled: bool) -> Result<()> {
+    let factory_config = &ctx.accounts.factory_config;
+    let fee_mint_config = &mut ctx.accounts.fee_mint_config;
+    let is_new = fee_mint_config.mint == Pubkey::default();
+    require!(
+        !factory_config.is_timelocked() || is_new,
+        FactoryError::TimelockRequired
+    );
+    require!(
+        !factory_config.is_multisig() || (is_new && !enabled),
+        FactoryError::MultisigRequired
+    );
+
+    fee_mint_config.mint = ctx.accounts.fee_mint.key();
+    fee_mint_config.treasury_ata = ctx.accounts.treasury_ata.key();
+    fee_mint_config.price = price;
+    fee_mint_config.enabled = enabled;
+    fee_mint_config.bump = ctx.bumps.fee_mint_config;
+
+    Ok(())
+}
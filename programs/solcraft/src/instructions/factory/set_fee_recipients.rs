@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::{FactoryError, GuardianError};
+use crate::states::{FactoryConfig, FeeRecipient, ProgramState};
+
+#[derive(Accounts)]
+pub struct SetFeeRecipients<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PAUSE_FACTORY)? @ GuardianError::ModulePaused,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
+        seeds = [FACTORY_CONFIG_SEEDS.as_bytes()],
+        bump = factory_config.bump,
+        has_one = admin @ FactoryError::Unauthorized,
+        constraint = !factory_config.is_multisig() @ FactoryError::MultisigRequired,
+        constraint = !factory_config.is_timelocked() @ FactoryError::TimelockRequired,
+    )]
+    pub factory_config: Account<'info, FactoryConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+/// Replaces the revenue split; an empty list disables `distribute_fees`.
+pub fn set_fee_recipients(
+    ctx: Context<SetFeeRecipients>,
+    fee_recipients: Vec<FeeRecipient>,
+) -> Result<()> {
+    validate_fee_recipients(&fee_recipients)?;
+
+    let factory_config = &mut ctx.accounts.factory_config;
+    factory_config.fee_recipients = fee_recipients;
+
+    Ok(())
+}
+
+/// Checks a revenue split before it replaces `FactoryConfig.fee_recipients`.
+pub(crate) fn validate_fee_recipients(fee_recipients: &[FeeRecipient]) -> Result<()> {
+    require!(
+        fee_recipients.len() <= MAX_FEE_RECIPIENTS as usize,
+        FactoryError::TooManyFeeRecipients
+    );
+
+    if !fee_recipients.is_empty() {
+        let mut total_bps: u32 = 0;
+        for (index, fee_recipient) in fee_recipients.iter().enumerate() {
+            require!(fee_recipient.bps > 0, FactoryError::InvalidFeeSplit);
+            require!(
+                fee_recipients[..index]
+                    .iter()
+                    .all(|other| other.recipient != fee_recipient.recipient),
+                FactoryError::InvalidFeeSplit
+            );
+            total_bps += fee_recipient.bps as u32;
+        }
+        require!(total_bps == MAX_BPS as u32, FactoryError::InvalidFeeSplit);
+    }
+
+    Ok(())
+}
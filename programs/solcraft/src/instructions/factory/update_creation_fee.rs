@@ -1,21 +1,36 @@
 use anchor_lang::prelude::*;
 
 use crate::constants::*;
-use crate::errors::FactoryError;
-use crate::states::FactoryConfig;
+use crate::errors::{FactoryError, GuardianError};
+use crate::states::{FactoryConfig, FactoryRole, ProgramState, Role};
 
 #[derive(Accounts)]
 #[instruction(creation_fee_lamports: u64)]
 pub struct UpdateCreationFee<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PAUSE_FACTORY)? @ GuardianError::ModulePaused,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
     #[account(
         mut,
         seeds = [FACTORY_CONFIG_SEEDS.as_bytes()],
         bump = factory_config.bump,
-        has_one = admin @ FactoryError::Unauthorized,
+        constraint = factory_config.has_role(&authority.key(), factory_role.as_deref(), Role::FeeManager) @ FactoryError::Unauthorized,
+        constraint = !factory_config.is_multisig() @ FactoryError::MultisigRequired,
+        constraint = !factory_config.is_timelocked() @ FactoryError::TimelockRequired,
     )]
     pub factory_config: Account<'info, FactoryConfig>,
 
-    pub admin: Signer<'info>,
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [FACTORY_ROLE_SEEDS.as_bytes(), factory_role.holder.as_ref(), &[factory_role.role as u8]],
+        bump = factory_role.bump,
+    )]
+    pub factory_role: Option<Account<'info, FactoryRole>>,
 }
 
 pub fn update_creation_fee(
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::{FactoryError, GuardianError};
+use crate::states::{FactoryConfig, FactoryRole, ProgramState, Role};
+
+#[derive(Accounts)]
+#[instruction(token_2022_surcharge_lamports: u64, volume_discount_threshold: u64, volume_discount_bps: u16)]
+pub struct UpdateFeeSchedule<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PAUSE_FACTORY)? @ GuardianError::ModulePaused,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
+        seeds = [FACTORY_CONFIG_SEEDS.as_bytes()],
+        bump = factory_config.bump,
+        constraint = factory_config.has_role(&authority.key(), factory_role.as_deref(), Role::FeeManager) @ FactoryError::Unauthorized,
+        constraint = !factory_config.is_multisig() @ FactoryError::MultisigRequired,
+        constraint = !factory_config.is_timelocked() @ FactoryError::TimelockRequired,
+    )]
+    pub factory_config: Account<'info, FactoryConfig>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [FACTORY_ROLE_SEEDS.as_bytes(), factory_role.holder.as_ref(), &[factory_role.role as u8]],
+        bump = factory_role.bump,
+    )]
+    pub factory_role: Option<Account<'info, FactoryRole>>,
+}
+
+/// A `volume_discount_threshold` of zero disables volume discounts.
+pub fn update_fee_schedule(
+    ctx: Context<UpdateFeeSchedule>,
+    token_2022_surcharge_lamports: u64,
+    volume_discount_threshold: u64,
+    volume_discount_bps: u16,
+) -> Result<()> {
+    require!(
+        volume_discount_bps <= MAX_BPS,
+        FactoryError::InvalidBasisPoints
+    );
+
+    let factory_config = &mut ctx.accounts.factory_config;
+    factory_config.token_2022_surcharge_lamports = token_2022_surcharge_lamports;
+    factory_config.volume_discount_threshold = volume_discount_threshold;
+    factory_config.volume_discount_bps = volume_discount_bps;
+
+    Ok(())
+}
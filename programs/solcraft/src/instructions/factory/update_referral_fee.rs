@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::{FactoryError, GuardianError};
+use crate::states::{FactoryConfig, ProgramState};
+
+#[derive(Accounts)]
+#[instruction(referral_fee_bps: u16)]
+pub struct UpdateReferralFee<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PAUSE_FACTORY)? @ GuardianError::ModulePaused,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
+        seeds = [FACTORY_CONFIG_SEEDS.as_bytes()],
+        bump = factory_config.bump,
+        has_one = admin @ FactoryError::Unauthorized,
+        constraint = !factory_config.is_multisig() @ FactoryError::MultisigRequired,
+    )]
+    pub factory_config: Account<'info, FactoryConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn update_referral_fee(ctx: Context<UpdateReferralFee>, referral_fee_bps: u16) -> Result<()> {
+    require!(
+        referral_fee_bps <= MAX_BPS,
+        FactoryError::InvalidBasisPoints
+    );
+
+    let factory_config = &mut ctx.accounts.factory_config;
+    factory_config.referral_fee_bps = referral_fee_bps;
+
+    Ok(())
+}
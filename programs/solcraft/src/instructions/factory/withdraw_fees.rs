@@ -1,15 +1,32 @@
 use anchor_lang::{prelude::*, system_program};
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
 
 use crate::constants::*;
-use crate::errors::FactoryError;
-use crate::states::FactoryConfig;
+use crate::errors::{FactoryError, GuardianError};
+use crate::states::{FactoryConfig, FactoryRole, ProgramState, Role};
 
+/// To also sweep SPL creation fees, pass `token_program` and one
+/// `[fee_mint, treasury_ata, destination_ata]` triple per fee mint as remaining accounts.
+/// All swept mints must belong to `token_program`. While the timelock is enabled, SPL fees
+/// can only be swept through a queued `AdminAction::SweepTokenFees`.
 #[derive(Accounts)]
 pub struct WithdrawFees<'info> {
     #[account(
+        seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PAUSE_FACTORY)? @ GuardianError::ModulePaused,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
         seeds = [FACTORY_CONFIG_SEEDS.as_bytes()],
         bump = factory_config.bump,
-        has_one = admin @ FactoryError::Unauthorized,
+        constraint = factory_config.has_role(&authority.key(), factory_role.as_deref(), Role::Treasurer) @ FactoryError::Unauthorized,
+        constraint = !factory_config.is_multisig() @ FactoryError::MultisigRequired,
     )]
     pub factory_config: Account<'info, FactoryConfig>,
 
@@ -21,38 +38,172 @@ pub struct WithdrawFees<'info> {
     )]
     pub treasury_account: SystemAccount<'info>,
 
+    /// CHECK: Any wallet chosen by the treasurer, e.g. a cold wallet; it must own any destination ATAs.
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub destination: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [FACTORY_ROLE_SEEDS.as_bytes(), factory_role.holder.as_ref(), &[factory_role.role as u8]],
+        bump = factory_role.bump,
+    )]
+    pub factory_role: Option<Account<'info, FactoryRole>>,
     pub system_program: Program<'info, System>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
 }
 
-pub fn withdraw_fees(ctx: Context<WithdrawFees>) -> Result<()> {
+/// Withdraws `amount` lamports, or everything above the rent-exempt minimum when `None`.
+/// A requested amount that the treasury cannot cover, or an empty treasury, fails instead of
+/// being skipped.
+pub fn withdraw_fees<'info>(
+    ctx: Context<'_, '_, 'info, 'info, WithdrawFees<'info>>,
+    amount: Option<u64>,
+) -> Result<()> {
+    let factory_config = &mut ctx.accounts.factory_config;
     let treasury_info = ctx.accounts.treasury_account.to_account_info();
-    let treasury_balance = treasury_info.lamports();
+    let withdraw_amount = treasury_withdraw_amount(&treasury_info, amount)?;
+
+    // Large withdrawals must go through the timelock while it is enabled.
+    factory_config.record_direct_withdrawal(withdraw_amount, Clock::get()?.unix_timestamp)?;
+
+    transfer_from_treasury(
+        factory_config,
+        &treasury_info,
+        &ctx.accounts.destination.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        withdraw_amount,
+    )?;
+
+    if ctx.remaining_accounts.is_empty() {
+        return Ok(());
+    }
+
+    require!(
+        !factory_config.is_timelocked(),
+        FactoryError::TimelockRequired
+    );
+    sweep_token_fees(
+        factory_config,
+        &treasury_info,
+        &ctx.accounts.destination.key(),
+        ctx.accounts.token_program.as_ref(),
+        ctx.remaining_accounts,
+    )
+}
 
+/// Moves the whole treasury balance of each `[fee_mint, treasury_ata, destination_ata]`
+/// triple in `remaining_accounts` to ATAs owned by `destination`.
+pub(crate) fn sweep_token_fees<'info>(
+    factory_config: &FactoryConfig,
+    treasury: &AccountInfo<'info>,
+    destination: &Pubkey,
+    token_program: Option<&Interface<'info, TokenInterface>>,
+    remaining_accounts: &'info [AccountInfo<'info>],
+) -> Result<()> {
+    let Some(token_program) = token_program else {
+        return err!(FactoryError::InvalidFeeMintAccounts);
+    };
+    require!(
+        !remaining_accounts.is_empty() && remaining_accounts.len() % 3 == 0,
+        FactoryError::InvalidFeeMintAccounts
+    );
+
+    let seeds = &[FACTORY_TREASURY.as_bytes(), &[factory_config.treasury_bump]];
+    let signer = &[&seeds[..]];
+
+    for accounts in remaining_accounts.chunks(3) {
+        let mint_info = &accounts[0];
+        let treasury_ata_info = &accounts[1];
+        let destination_ata_info = &accounts[2];
+
+        require_keys_eq!(
+            treasury_ata_info.key(),
+            get_associated_token_address_with_program_id(
+                &treasury.key(),
+                &mint_info.key(),
+                &token_program.key(),
+            ),
+            FactoryError::InvalidFeeMintAccounts
+        );
+        let mint = InterfaceAccount::<Mint>::try_from(mint_info)?;
+        let treasury_ata = InterfaceAccount::<TokenAccount>::try_from(treasury_ata_info)?;
+        let destination_ata = InterfaceAccount::<TokenAccount>::try_from(destination_ata_info)?;
+        require!(
+            destination_ata.mint == mint.key() && destination_ata.owner == *destination,
+            FactoryError::InvalidFeeMintAccounts
+        );
+
+        if treasury_ata.amount == 0 {
+            continue;
+        }
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                TransferChecked {
+                    from: treasury_ata_info.clone(),
+                    mint: mint_info.clone(),
+                    to: destination_ata_info.clone(),
+                    authority: treasury.clone(),
+                },
+                signer,
+            ),
+            treasury_ata.amount,
+            mint.decimals,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Resolves a requested lamport withdrawal against what the treasury can pay while staying
+/// rent-exempt. `None` means everything above the rent-exempt minimum.
+pub(crate) fn treasury_withdraw_amount(treasury: &AccountInfo, amount: Option<u64>) -> Result<u64> {
     // Calculate the amount that can be withdrawn while keeping the treasury account rent-exempt.
-    let rent_exempt_minimum = Rent::get()?.minimum_balance(treasury_info.data_len());
-    let withdrawable_amount = treasury_balance.saturating_sub(rent_exempt_minimum);
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(treasury.data_len());
+    let withdrawable_amount = treasury.lamports().saturating_sub(rent_exempt_minimum);
+
+    match amount {
+        Some(amount) => {
+            require!(
+                amount > 0 && amount <= withdrawable_amount,
+                FactoryError::InsufficientFundsToWithdraw
+            );
+            Ok(amount)
+        }
+        None => {
+            require!(
+                withdrawable_amount > 0,
+                FactoryError::InsufficientFundsToWithdraw
+            );
+            Ok(withdrawable_amount)
+        }
+    }
+}
 
-    if withdrawable_amount == 0 {
+pub(crate) fn transfer_from_treasury<'info>(
+    factory_config: &FactoryConfig,
+    treasury: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
         return Ok(());
     }
 
-    let seeds = &[
-        FACTORY_TREASURY.as_bytes(),
-        &[ctx.accounts.factory_config.treasury_bump],
-    ];
+    let seeds = &[FACTORY_TREASURY.as_bytes(), &[factory_config.treasury_bump]];
     let signer = &[&seeds[..]];
 
     let cpi_ctx = CpiContext::new_with_signer(
-        ctx.accounts.system_program.to_account_info(),
+        system_program.clone(),
         system_program::Transfer {
-            from: ctx.accounts.treasury_account.to_account_info(),
-            to: ctx.accounts.admin.to_account_info(),
+            from: treasury.clone(),
+            to: destination.clone(),
         },
         signer,
     );
-    system_program::transfer(cpi_ctx, withdrawable_amount)?;
-
-    Ok(())
+    system_program::transfer(cpi_ctx, amount)
 }
@@ -1,9 +1,43 @@
 pub mod claim;
+pub mod claim_sponsored;
+pub mod close;
+pub mod close_recipient_data;
+pub mod configure_drip;
+pub mod configure_referrals;
 pub mod deposit;
 pub mod initialize;
+pub mod migrate_config;
+pub mod migrate_recipient_data;
+pub mod pause;
+pub mod set_cooldown_mode;
+pub mod set_epoch_claim_limit;
+pub mod set_operator;
+pub mod set_rent_sponsorship;
+pub mod set_sponsored_pool;
+pub mod unpause;
+pub mod update_settings;
 pub mod withdraw;
+pub mod withdraw_rent_vault;
+pub mod withdraw_sponsor_share;
 
 pub use claim::*;
+pub use claim_sponsored::*;
+pub use close::*;
+pub use close_recipient_data::*;
+pub use configure_drip::*;
+pub use configure_referrals::*;
 pub use deposit::*;
 pub use initialize::*;
+pub use migrate_config::*;
+pub use migrate_recipient_data::*;
+pub use pause::*;
+pub use set_cooldown_mode::*;
+pub use set_epoch_claim_limit::*;
+pub use set_operator::*;
+pub use set_rent_sponsorship::*;
+pub use set_sponsored_pool::*;
+pub use unpause::*;
+pub use update_settings::*;
 pub use withdraw::*;
+pub use withdraw_rent_vault::*;
+pub use withdraw_sponsor_share::*;
@@ -1,17 +1,28 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{transfer, Transfer};
-use anchor_spl::token_interface::{TokenAccount, TokenInterface};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
-use crate::constants::{DISCRIMINATOR, FAUCET_CONFIG_SEEDS, FAUCET_RECIPIENT_SEEDS};
-use crate::errors::FaucetError;
-use crate::states::{FaucetConfig, FaucetRecipientData};
+use crate::constants::{
+    DISCRIMINATOR, FAUCET_CONFIG_SEEDS, FAUCET_RECIPIENT_SEEDS, PAUSE_FAUCET, PROGRAM_STATE_SEEDS,
+};
+use crate::errors::{FaucetError, GuardianError};
+use crate::events::{FaucetClaimed, FaucetReferral};
+use crate::states::{FaucetConfig, FaucetRecipientData, ProgramState};
+use crate::utils::{gross_transfer_amount, transfer_checked_with_hooks};
 
 #[derive(Accounts)]
 pub struct Claim<'info> {
+    #[account(
+      seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+      bump = program_state.bump,
+      constraint = !program_state.is_paused(PAUSE_FAUCET)? @ GuardianError::ModulePaused,
+   )]
+    pub program_state: Account<'info, ProgramState>,
+
     #[account(
       mut,
       seeds = [FAUCET_CONFIG_SEEDS.as_bytes()],
       bump = faucet_config.bump,
+      constraint = !faucet_config.paused @ FaucetError::FaucetPaused,
    )]
     pub faucet_config: Account<'info, FaucetConfig>,
 
@@ -24,10 +35,14 @@ pub struct Claim<'info> {
     )]
     pub recipient_data: Account<'info, FaucetRecipientData>,
 
+    #[account(address = faucet_config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
     #[account(
         mut,
         associated_token::mint = faucet_config.mint,
         associated_token::authority = faucet_config,
+        associated_token::token_program = token_program,
         constraint = faucet_config.treasury_ata == treasury_ata.key() @ FaucetError::InvalidTreasuryAta,
     )]
     pub treasury_ata: InterfaceAccount<'info, TokenAccount>,
@@ -36,55 +51,196 @@ pub struct Claim<'info> {
         mut,
         associated_token::mint = faucet_config.mint,
         associated_token::authority = recipient,
+        associated_token::token_program = token_program,
     )]
     pub recipient_ata: InterfaceAccount<'info, TokenAccount>,
 
     #[account(mut)]
     pub recipient: Signer<'info>,
 
+    /// CHECK: Optional referrer wallet; validated against `referrer_data` and `referrer_ata` in the handler.
+    pub referrer: Option<UncheckedAccount<'info>>,
+
+    // The referrer's own recipient record; only wallets that have claimed can refer others.
+    #[account(mut)]
+    pub referrer_data: Option<Account<'info, FaucetRecipientData>>,
+
+    #[account(mut)]
+    pub referrer_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
     pub token_program: Interface<'info, TokenInterface>,
 
     pub system_program: Program<'info, System>,
 }
 
-pub fn claim(ctx: Context<Claim>) -> Result<()> {
-    let faucet_config = &ctx.accounts.faucet_config;
-    let claim_amount = ctx.accounts.faucet_config.allowed_claim_amount;
-    let treasury_balance = ctx.accounts.treasury_ata.amount.clone();
+pub fn claim<'info>(mut ctx: Context<'_, '_, 'info, 'info, Claim<'info>>) -> Result<()> {
+    let clock = Clock::get()?;
+    let is_first_claim = !ctx.accounts.recipient_data.has_claimed();
+    if is_first_claim {
+        ctx.accounts
+            .recipient_data
+            .initialize(ctx.accounts.recipient.key());
+    }
+
+    let remaining_treasury = pay_claim(
+        &mut ctx.accounts.faucet_config,
+        &mut ctx.accounts.recipient_data,
+        &ctx.accounts.mint,
+        &ctx.accounts.treasury_ata,
+        &ctx.accounts.recipient_ata,
+        &ctx.accounts.recipient.key(),
+        &ctx.accounts.token_program,
+        ctx.remaining_accounts,
+        &clock,
+    )?;
+
+    if is_first_claim && ctx.accounts.referrer.is_some() {
+        pay_referral_bonus(&mut ctx, remaining_treasury, &clock)?;
+    }
+
+    Ok(())
+}
+
+/// Pays the recipient's current entitlement out of the treasury and records the claim against
+/// both the recipient and the faucet. Returns the treasury balance left after the transfer.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn pay_claim<'info>(
+    faucet_config: &mut Account<'info, FaucetConfig>,
+    recipient_data: &mut Account<'info, FaucetRecipientData>,
+    mint: &InterfaceAccount<'info, Mint>,
+    treasury_ata: &InterfaceAccount<'info, TokenAccount>,
+    recipient_ata: &InterfaceAccount<'info, TokenAccount>,
+    recipient: &Pubkey,
+    token_program: &Interface<'info, TokenInterface>,
+    remaining_accounts: &[AccountInfo<'info>],
+    clock: &Clock,
+) -> Result<u64> {
+    let claim_amount = faucet_config.claimable_amount(recipient_data, clock)?;
+
+    // Send enough to cover any Token-2022 transfer fee so the recipient receives the full claim.
+    let mint_info = mint.to_account_info();
+    let gross_amount = gross_transfer_amount(&mint_info, claim_amount)?;
+    let treasury_balance = treasury_ata.amount;
     require!(
-        treasury_balance >= claim_amount,
+        treasury_balance >= gross_amount,
         FaucetError::InsufficientFunds
     );
 
-    let last_claimed_at = ctx.accounts.recipient_data.last_claimed_at;
+    let seeds = &[FAUCET_CONFIG_SEEDS.as_bytes(), &[faucet_config.bump]];
+
+    transfer_checked_with_hooks(
+        &token_program.to_account_info(),
+        &treasury_ata.to_account_info(),
+        &mint_info,
+        &recipient_ata.to_account_info(),
+        &faucet_config.to_account_info(),
+        remaining_accounts,
+        gross_amount,
+        mint.decimals,
+        &[seeds],
+    )?;
+
+    recipient_data.record_claim(clock);
+    faucet_config.record_epoch_claim(clock)?;
+    faucet_config.total_claimed = faucet_config.total_claimed.saturating_add(gross_amount);
+
+    emit!(FaucetClaimed {
+        faucet: faucet_config.key(),
+        recipient: *recipient,
+        amount: claim_amount,
+        faucet_total: faucet_config.total_claimed,
+    });
+
+    Ok(treasury_balance - gross_amount)
+}
+
+fn pay_referral_bonus<'info>(
+    ctx: &mut Context<'_, '_, 'info, 'info, Claim<'info>>,
+    remaining_treasury: u64,
+    clock: &Clock,
+) -> Result<()> {
+    let remaining_accounts = ctx.remaining_accounts;
+    let Claim {
+        faucet_config,
+        recipient_data,
+        mint,
+        treasury_ata,
+        recipient,
+        referrer,
+        referrer_data,
+        referrer_ata,
+        token_program,
+        ..
+    } = &mut *ctx.accounts;
 
-    if last_claimed_at != 0 {
-        let current_timestamp = Clock::get()?.unix_timestamp;
-        require!(
-            current_timestamp - last_claimed_at >= faucet_config.cooldown_seconds as i64,
-            FaucetError::CooldownNotElapsed
-        );
+    if faucet_config.referral_bonus_amount == 0 {
+        return Ok(());
     }
 
-    let seeds = &[
-        FAUCET_CONFIG_SEEDS.as_bytes(),
-        &[ctx.accounts.faucet_config.bump],
-    ];
-
-    transfer(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.treasury_ata.to_account_info(),
-                to: ctx.accounts.recipient_ata.to_account_info(),
-                authority: ctx.accounts.faucet_config.to_account_info(),
-            },
+    let (Some(referrer), Some(referrer_data), Some(referrer_ata)) =
+        (referrer, referrer_data, referrer_ata)
+    else {
+        return err!(FaucetError::InvalidReferrer);
+    };
+    let referrer_key = referrer.key();
+    let recipient_key = recipient.key();
+
+    require_keys_neq!(referrer_key, recipient_key, FaucetError::SelfReferral);
+    require!(
+        referrer_data.referrer != Some(recipient_key),
+        FaucetError::ReferralCycle
+    );
+
+    let (expected_referrer_data, _) = Pubkey::find_program_address(
+        &[FAUCET_RECIPIENT_SEEDS.as_bytes(), referrer_key.as_ref()],
+        ctx.program_id,
+    );
+    require!(
+        referrer_data.key() == expected_referrer_data && referrer_data.has_claimed(),
+        FaucetError::InvalidReferrer
+    );
+    require!(
+        referrer_ata.mint == faucet_config.mint && referrer_ata.owner == referrer_key,
+        FaucetError::InvalidReferrer
+    );
+
+    recipient_data.referrer = Some(referrer_key);
+    referrer_data.referral_count += 1;
+
+    // The referral is recorded even when the bonus itself is rate-limited or unfunded.
+    let mint_info = mint.to_account_info();
+    let gross_bonus = gross_transfer_amount(&mint_info, faucet_config.referral_bonus_amount)?;
+    let rewarded_recently = referrer_data.last_referral_rewarded_at != 0
+        && clock.unix_timestamp - referrer_data.last_referral_rewarded_at
+            < faucet_config.referral_cooldown_seconds as i64;
+
+    let mut bonus = 0;
+    if !rewarded_recently && remaining_treasury >= gross_bonus {
+        let seeds = &[FAUCET_CONFIG_SEEDS.as_bytes(), &[faucet_config.bump]];
+
+        transfer_checked_with_hooks(
+            &token_program.to_account_info(),
+            &treasury_ata.to_account_info(),
+            &mint_info,
+            &referrer_ata.to_account_info(),
+            &faucet_config.to_account_info(),
+            remaining_accounts,
+            gross_bonus,
+            mint.decimals,
             &[seeds],
-        ),
-        claim_amount,
-    )?;
+        )?;
+
+        bonus = faucet_config.referral_bonus_amount;
+        referrer_data.last_referral_rewarded_at = clock.unix_timestamp;
+        faucet_config.total_claimed = faucet_config.total_claimed.saturating_add(gross_bonus);
+    }
 
-    ctx.accounts.recipient_data.last_claimed_at = Clock::get()?.unix_timestamp;
+    emit!(FaucetReferral {
+        faucet: faucet_config.key(),
+        recipient: recipient_key,
+        referrer: referrer_key,
+        bonus,
+    });
 
     Ok(())
 }
@@ -0,0 +1,138 @@
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::{
+    DISCRIMINATOR, FAUCET_CONFIG_SEEDS, FAUCET_RECIPIENT_SEEDS, FAUCET_RENT_VAULT_SEEDS,
+    PAUSE_FAUCET, PROGRAM_STATE_SEEDS,
+};
+use crate::errors::{FaucetError, GuardianError};
+use crate::instructions::faucet::claim::pay_claim;
+use crate::states::{FaucetConfig, FaucetRecipientData, ProgramState};
+
+#[derive(Accounts)]
+pub struct ClaimSponsored<'info> {
+    #[account(
+      seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+      bump = program_state.bump,
+      constraint = !program_state.is_paused(PAUSE_FAUCET)? @ GuardianError::ModulePaused,
+   )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+      mut,
+      seeds = [FAUCET_CONFIG_SEEDS.as_bytes()],
+      bump = faucet_config.bump,
+      constraint = !faucet_config.paused @ FaucetError::FaucetPaused,
+   )]
+    pub faucet_config: Account<'info, FaucetConfig>,
+
+    #[account(
+      init_if_needed,
+      payer = fee_payer,
+      space = DISCRIMINATOR + FaucetRecipientData::INIT_SPACE,
+      seeds = [FAUCET_RECIPIENT_SEEDS.as_bytes(), recipient.key().as_ref()],
+      bump,
+    )]
+    pub recipient_data: Account<'info, FaucetRecipientData>,
+
+    #[account(address = faucet_config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = faucet_config.mint,
+        associated_token::authority = faucet_config,
+        associated_token::token_program = token_program,
+        constraint = faucet_config.treasury_ata == treasury_ata.key() @ FaucetError::InvalidTreasuryAta,
+    )]
+    pub treasury_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = fee_payer,
+        associated_token::mint = mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program,
+    )]
+    pub recipient_ata: InterfaceAccount<'info, TokenAccount>,
+
+    // The recipient still signs the claim but does not need to hold any SOL.
+    pub recipient: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [FAUCET_RENT_VAULT_SEEDS.as_bytes()],
+        bump,
+    )]
+    pub rent_vault: SystemAccount<'info>,
+
+    // Relayer that pays transaction fees and rent for the recipient's accounts.
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn claim_sponsored<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimSponsored<'info>>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // A zeroed record means it was just created by this instruction, so the fee payer
+    // covered its rent and is eligible for reimbursement.
+    if !ctx.accounts.recipient_data.has_claimed() {
+        let rent_payer = if ctx.accounts.faucet_config.sponsor_rent && reimburse_rent(&ctx)? {
+            ctx.accounts.rent_vault.key()
+        } else {
+            ctx.accounts.fee_payer.key()
+        };
+        ctx.accounts.recipient_data.initialize(rent_payer);
+    }
+
+    pay_claim(
+        &mut ctx.accounts.faucet_config,
+        &mut ctx.accounts.recipient_data,
+        &ctx.accounts.mint,
+        &ctx.accounts.treasury_ata,
+        &ctx.accounts.recipient_ata,
+        &ctx.accounts.recipient.key(),
+        &ctx.accounts.token_program,
+        ctx.remaining_accounts,
+        &clock,
+    )?;
+
+    Ok(())
+}
+
+/// Refunds the fee payer for the record's rent out of the rent vault. Returns false when the
+/// vault cannot cover it without dropping below its own rent-exempt floor.
+fn reimburse_rent(ctx: &Context<ClaimSponsored>) -> Result<bool> {
+    let rent = Rent::get()?;
+    let reimbursement = rent.minimum_balance(DISCRIMINATOR + FaucetRecipientData::INIT_SPACE);
+
+    let vault_info = ctx.accounts.rent_vault.to_account_info();
+    let vault_floor = rent.minimum_balance(vault_info.data_len());
+    let available = vault_info.lamports().saturating_sub(vault_floor);
+    if available < reimbursement {
+        return Ok(false);
+    }
+
+    let vault_seeds = &[FAUCET_RENT_VAULT_SEEDS.as_bytes(), &[ctx.bumps.rent_vault]];
+
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.rent_vault.to_account_info(),
+                to: ctx.accounts.fee_payer.to_account_info(),
+            },
+            &[vault_seeds],
+        ),
+        reimbursement,
+    )?;
+
+    Ok(true)
+}
@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    close_account, CloseAccount, Mint, TokenAccount, TokenInterface,
+};
+
+use crate::constants::{
+    DISCRIMINATOR, FAUCET_CONFIG_SEEDS, FAUCET_POOL_COUNTER_SEEDS, PAUSE_FAUCET,
+    PROGRAM_STATE_SEEDS,
+};
+use crate::errors::{FaucetError, GuardianError};
+use crate::states::{FaucetConfig, FaucetPoolCounter, ProgramState};
+use crate::utils::{harvest_withheld_fees, transfer_checked_with_hooks};
+
+#[derive(Accounts)]
+pub struct CloseFaucet<'info> {
+    #[account(
+      seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+      bump = program_state.bump,
+      constraint = !program_state.is_paused(PAUSE_FAUCET)? @ GuardianError::ModulePaused,
+   )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+      mut,
+      seeds = [FAUCET_CONFIG_SEEDS.as_bytes()],
+      bump = faucet_config.bump,
+      has_one = owner @ FaucetError::Unauthorized,
+      close = owner,
+   )]
+    pub faucet_config: Account<'info, FaucetConfig>,
+
+    // Created here for faucets initialized before the counter existed.
+    #[account(
+      init_if_needed,
+      payer = owner,
+      space = DISCRIMINATOR + FaucetPoolCounter::INIT_SPACE,
+      seeds = [FAUCET_POOL_COUNTER_SEEDS.as_bytes()],
+      bump,
+   )]
+    pub pool_counter: Account<'info, FaucetPoolCounter>,
+
+    // Writable so withheld Token-2022 transfer fees can be harvested into it.
+    #[account(mut, address = faucet_config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = faucet_config.mint,
+        associated_token::authority = faucet_config,
+        associated_token::token_program = token_program,
+        constraint = faucet_config.treasury_ata == treasury_ata.key() @ FaucetError::InvalidTreasuryAta,
+    )]
+    pub treasury_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = faucet_config.mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub owner_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn close<'info>(ctx: Context<'_, '_, 'info, 'info, CloseFaucet<'info>>) -> Result<()> {
+    let treasury_balance = ctx.accounts.treasury_ata.amount;
+
+    // Sponsors must redeem their shares before the pool can be swept to the owner.
+    require!(
+        ctx.accounts.faucet_config.total_shares == 0 || treasury_balance == 0,
+        FaucetError::PoolNotEmpty
+    );
+
+    // Depositor records outlive the faucet, so a re-initialized faucet starts a fresh generation.
+    let pool_counter = &mut ctx.accounts.pool_counter;
+    pool_counter.next_pool_generation = ctx
+        .accounts
+        .faucet_config
+        .pool_generation
+        .checked_add(1)
+        .ok_or(FaucetError::MathOverflow)?;
+    pool_counter.bump = ctx.bumps.pool_counter;
+
+    let seeds = &[
+        FAUCET_CONFIG_SEEDS.as_bytes(),
+        &[ctx.accounts.faucet_config.bump],
+    ];
+
+    // Sweep whatever is left in the treasury back to the owner before closing it.
+    if treasury_balance > 0 {
+        transfer_checked_with_hooks(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.treasury_ata.to_account_info(),
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.owner_ata.to_account_info(),
+            &ctx.accounts.faucet_config.to_account_info(),
+            ctx.remaining_accounts,
+            treasury_balance,
+            ctx.accounts.mint.decimals,
+            &[seeds],
+        )?;
+    }
+
+    harvest_withheld_fees(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.treasury_ata.to_account_info(),
+    )?;
+
+    // Close the treasury token account and return its rent to the owner.
+    // The faucet config itself is closed by the `close = owner` constraint.
+    close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.treasury_ata.to_account_info(),
+            destination: ctx.accounts.owner.to_account_info(),
+            authority: ctx.accounts.faucet_config.to_account_info(),
+        },
+        &[seeds],
+    ))?;
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{
+    FAUCET_CONFIG_SEEDS, FAUCET_RECIPIENT_SEEDS, PAUSE_FAUCET, PROGRAM_STATE_SEEDS,
+};
+use crate::errors::{FaucetError, GuardianError};
+use crate::states::{FaucetConfig, FaucetRecipientData, ProgramState};
+
+#[derive(Accounts)]
+pub struct CloseRecipientData<'info> {
+    #[account(
+      seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+      bump = program_state.bump,
+      constraint = !program_state.is_paused(PAUSE_FAUCET)? @ GuardianError::ModulePaused,
+   )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+      seeds = [FAUCET_CONFIG_SEEDS.as_bytes()],
+      bump = faucet_config.bump,
+   )]
+    pub faucet_config: Account<'info, FaucetConfig>,
+
+    #[account(
+      mut,
+      seeds = [FAUCET_RECIPIENT_SEEDS.as_bytes(), recipient.key().as_ref()],
+      bump,
+      close = rent_payer,
+    )]
+    pub recipient_data: Account<'info, FaucetRecipientData>,
+
+    pub recipient: Signer<'info>,
+
+    /// CHECK: Refunded the record's rent; must match the payer stored on the record.
+    #[account(mut, address = recipient_data.rent_payer @ FaucetError::InvalidRentPayer)]
+    pub rent_payer: UncheckedAccount<'info>,
+}
+
+pub fn close_recipient_data(ctx: Context<CloseRecipientData>) -> Result<()> {
+    require!(
+        !ctx.accounts.recipient_data.has_referral_state(),
+        FaucetError::ReferralRecordLocked
+    );
+
+    // Only allow closing once the recipient could claim in full again, otherwise closing
+    // and re-opening the record would reset the cooldown or refill the drip bucket.
+    let clock = Clock::get()?;
+    require!(
+        ctx.accounts
+            .faucet_config
+            .is_fully_recharged(&ctx.accounts.recipient_data, &clock),
+        FaucetError::CooldownNotElapsed
+    );
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{FAUCET_CONFIG_SEEDS, PAUSE_FAUCET, PROGRAM_STATE_SEEDS};
+use crate::errors::{FaucetError, GuardianError};
+use crate::states::{FaucetConfig, ProgramState};
+
+#[derive(Accounts)]
+#[instruction(rate_per_second: u64, cap: u64)]
+pub struct ConfigureDrip<'info> {
+    #[account(
+      seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+      bump = program_state.bump,
+      constraint = !program_state.is_paused(PAUSE_FAUCET)? @ GuardianError::ModulePaused,
+   )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+      mut,
+      seeds = [FAUCET_CONFIG_SEEDS.as_bytes()],
+      bump = faucet_config.bump,
+      constraint = faucet_config.is_owner_or_operator(&authority.key()) @ FaucetError::Unauthorized,
+   )]
+    pub faucet_config: Account<'info, FaucetConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Switches the faucet to drip mode; a `rate_per_second` of zero restores cooldown mode.
+pub fn configure_drip(ctx: Context<ConfigureDrip>, rate_per_second: u64, cap: u64) -> Result<()> {
+    require!(
+        rate_per_second == 0 || cap > 0,
+        FaucetError::InvalidDripConfig
+    );
+
+    let faucet_config = &mut ctx.accounts.faucet_config;
+    if rate_per_second > 0 {
+        // An emptied bucket refills completely in `cap / rate` seconds.
+        faucet_config.check_operator_bounds(
+            &ctx.accounts.authority.key(),
+            cap,
+            cap.div_ceil(rate_per_second),
+        )?;
+    }
+
+    faucet_config.drip_rate_per_second = rate_per_second;
+    faucet_config.drip_cap = cap;
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{FAUCET_CONFIG_SEEDS, PAUSE_FAUCET, PROGRAM_STATE_SEEDS};
+use crate::errors::{FaucetError, GuardianError};
+use crate::states::{FaucetConfig, ProgramState};
+
+#[derive(Accounts)]
+#[instruction(bonus_amount: u64, cooldown_seconds: u64)]
+pub struct ConfigureReferrals<'info> {
+    #[account(
+      seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+      bump = program_state.bump,
+      constraint = !program_state.is_paused(PAUSE_FAUCET)? @ GuardianError::ModulePaused,
+   )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+      mut,
+      seeds = [FAUCET_CONFIG_SEEDS.as_bytes()],
+      bump = faucet_config.bump,
+      constraint = faucet_config.is_owner_or_operator(&authority.key()) @ FaucetError::Unauthorized,
+   )]
+    pub faucet_config: Account<'info, FaucetConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+/// A `bonus_amount` of zero disables referral rewards.
+pub fn configure_referrals(
+    ctx: Context<ConfigureReferrals>,
+    bonus_amount: u64,
+    cooldown_seconds: u64,
+) -> Result<()> {
+    let faucet_config = &mut ctx.accounts.faucet_config;
+    faucet_config.check_operator_bounds(
+        &ctx.accounts.authority.key(),
+        bonus_amount,
+        cooldown_seconds,
+    )?;
+
+    faucet_config.referral_bonus_amount = bonus_amount;
+    faucet_config.referral_cooldown_seconds = cooldown_seconds;
+
+    Ok(())
+}
@@ -1,14 +1,24 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{transfer, Transfer};
-use anchor_spl::token_interface::{TokenAccount, TokenInterface};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
-use crate::constants::FAUCET_CONFIG_SEEDS;
-use crate::errors::FaucetError;
-use crate::states::FaucetConfig;
+use crate::constants::{
+    DISCRIMINATOR, FAUCET_CONFIG_SEEDS, FAUCET_DEPOSITOR_SEEDS, PAUSE_FAUCET, PROGRAM_STATE_SEEDS,
+};
+use crate::errors::{FaucetError, GuardianError};
+use crate::events::FaucetDeposited;
+use crate::states::{FaucetConfig, FaucetDepositorRecord, ProgramState};
+use crate::utils::{transfer_checked_with_hooks, transfer_fee};
 
 #[derive(Accounts)]
 #[instruction(amount: u64)]
 pub struct Deposit<'info> {
+    #[account(
+      seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+      bump = program_state.bump,
+      constraint = !program_state.is_paused(PAUSE_FAUCET)? @ GuardianError::ModulePaused,
+   )]
+    pub program_state: Account<'info, ProgramState>,
+
     #[account(
       mut,
       seeds = [FAUCET_CONFIG_SEEDS.as_bytes()],
@@ -16,10 +26,14 @@ pub struct Deposit<'info> {
    )]
     pub faucet_config: Account<'info, FaucetConfig>,
 
+    #[account(address = faucet_config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
     #[account(
         mut,
         associated_token::mint = faucet_config.mint,
         associated_token::authority = faucet_config,
+        associated_token::token_program = token_program,
         constraint = faucet_config.treasury_ata == treasury_ata.key() @ FaucetError::InvalidTreasuryAta,
     )]
     pub treasury_ata: InterfaceAccount<'info, TokenAccount>,
@@ -28,29 +42,100 @@ pub struct Deposit<'info> {
         mut,
         associated_token::mint = faucet_config.mint,
         associated_token::authority = depositor,
+        associated_token::token_program = token_program,
     )]
     pub depositor_ata: InterfaceAccount<'info, TokenAccount>,
 
+    #[account(
+      init_if_needed,
+      payer = depositor,
+      space = DISCRIMINATOR + FaucetDepositorRecord::INIT_SPACE,
+      seeds = [
+        FAUCET_DEPOSITOR_SEEDS.as_bytes(),
+        faucet_config.key().as_ref(),
+        depositor.key().as_ref(),
+      ],
+      bump,
+    )]
+    pub depositor_record: Account<'info, FaucetDepositorRecord>,
+
+    #[account(mut)]
     pub depositor: Signer<'info>,
 
     pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
 }
 
-pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
-    let depositor_balance = ctx.accounts.depositor_ata.amount.clone();
+pub fn deposit<'info>(
+    ctx: Context<'_, '_, 'info, 'info, Deposit<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let depositor_balance = ctx.accounts.depositor_ata.amount;
     require!(depositor_balance >= amount, FaucetError::InsufficientFunds);
 
-    transfer(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.depositor_ata.to_account_info(),
-                to: ctx.accounts.treasury_ata.to_account_info(),
-                authority: ctx.accounts.depositor.to_account_info(),
-            },
-        ),
+    let pool_balance = ctx.accounts.treasury_ata.amount;
+
+    transfer_checked_with_hooks(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.depositor_ata.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.treasury_ata.to_account_info(),
+        &ctx.accounts.depositor.to_account_info(),
+        ctx.remaining_accounts,
         amount,
+        ctx.accounts.mint.decimals,
+        &[],
     )?;
 
+    // Token-2022 transfer fees are withheld from the deposit, so the treasury is credited less.
+    let withheld_fee = transfer_fee(&ctx.accounts.mint.to_account_info(), amount)?;
+    let credited_amount = amount - withheld_fee;
+    let current_timestamp = Clock::get()?.unix_timestamp;
+
+    let faucet_config = &mut ctx.accounts.faucet_config;
+    faucet_config.total_deposited = faucet_config
+        .total_deposited
+        .saturating_add(credited_amount);
+
+    let depositor_record = &mut ctx.accounts.depositor_record;
+    depositor_record.faucet = faucet_config.key();
+    depositor_record.depositor = ctx.accounts.depositor.key();
+    depositor_record.total_deposited = depositor_record
+        .total_deposited
+        .saturating_add(credited_amount);
+    depositor_record.last_deposited_at = current_timestamp;
+    depositor_record.bump = ctx.bumps.depositor_record;
+
+    if faucet_config.sponsored_pool {
+        // Claims drained the pool, so outstanding shares no longer back any tokens.
+        if faucet_config.total_shares > 0 && pool_balance == 0 {
+            faucet_config.total_shares = 0;
+            faucet_config.pool_generation += 1;
+        }
+
+        let shares = faucet_config.shares_for_deposit(credited_amount, pool_balance)?;
+        // Tokens sent straight to the treasury can inflate the share price until small deposits
+        // round down to nothing, which would hand them to the existing sponsors.
+        require!(shares > 0, FaucetError::DepositTooSmall);
+        depositor_record.shares = depositor_record
+            .active_shares(faucet_config)
+            .checked_add(shares)
+            .ok_or(FaucetError::MathOverflow)?;
+        depositor_record.pool_generation = faucet_config.pool_generation;
+        faucet_config.total_shares = faucet_config
+            .total_shares
+            .checked_add(shares)
+            .ok_or(FaucetError::MathOverflow)?;
+    }
+
+    emit!(FaucetDeposited {
+        faucet: depositor_record.faucet,
+        depositor: depositor_record.depositor,
+        amount: credited_amount,
+        withheld_fee,
+        depositor_total: depositor_record.total_deposited,
+        faucet_total: faucet_config.total_deposited,
+    });
+
     Ok(())
 }
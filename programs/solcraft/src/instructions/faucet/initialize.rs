@@ -2,12 +2,22 @@ use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
-use crate::constants::{DISCRIMINATOR, FAUCET_CONFIG_SEEDS, MAX_DECIMALS};
-use crate::errors::FaucetError;
-use crate::states::FaucetConfig;
+use crate::constants::{
+    CONFIG_RESERVED_BYTES, DISCRIMINATOR, FAUCET_CONFIG_SEEDS, FAUCET_CONFIG_VERSION,
+    FAUCET_POOL_COUNTER_SEEDS, MAX_DECIMALS, PAUSE_FAUCET, PROGRAM_STATE_SEEDS,
+};
+use crate::errors::{FaucetError, GuardianError};
+use crate::states::{CooldownMode, FaucetConfig, FaucetPoolCounter, ProgramState};
 
 #[derive(Accounts)]
 pub struct InitializeFaucet<'info> {
+    #[account(
+      seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+      bump = program_state.bump,
+      constraint = !program_state.is_paused(PAUSE_FAUCET)? @ GuardianError::ModulePaused,
+   )]
+    pub program_state: Account<'info, ProgramState>,
+
     #[account(
       init,
       payer = owner,
@@ -17,6 +27,15 @@ pub struct InitializeFaucet<'info> {
    )]
     pub faucet_config: Account<'info, FaucetConfig>,
 
+    #[account(
+      init_if_needed,
+      payer = owner,
+      space = DISCRIMINATOR + FaucetPoolCounter::INIT_SPACE,
+      seeds = [FAUCET_POOL_COUNTER_SEEDS.as_bytes()],
+      bump,
+   )]
+    pub pool_counter: Account<'info, FaucetPoolCounter>,
+
     pub mint: InterfaceAccount<'info, Mint>,
 
     #[account(
@@ -24,6 +43,7 @@ pub struct InitializeFaucet<'info> {
       payer = owner,
       associated_token::mint = mint,
       associated_token::authority = faucet_config,
+      associated_token::token_program = token_program,
     )]
     pub treasury_ata: InterfaceAccount<'info, TokenAccount>,
 
@@ -40,12 +60,36 @@ pub fn initialize(ctx: Context<InitializeFaucet>) -> Result<()> {
     let decimals = ctx.accounts.mint.decimals;
     require!(decimals <= MAX_DECIMALS, FaucetError::ExceedsMaxDecimals);
 
+    faucet_config.version = FAUCET_CONFIG_VERSION;
     faucet_config.owner = ctx.accounts.owner.key();
     faucet_config.mint = ctx.accounts.mint.key();
     faucet_config.treasury_ata = ctx.accounts.treasury_ata.key();
     faucet_config.cooldown_seconds = 3600; // default 1 hour
     faucet_config.allowed_claim_amount = 1000 * 10u64.pow(decimals as u32); // default 1000 tokens
     faucet_config.bump = ctx.bumps.faucet_config;
+    faucet_config.sponsor_rent = false;
+    faucet_config.total_deposited = 0;
+    faucet_config.total_claimed = 0;
+    faucet_config.sponsored_pool = false;
+    faucet_config.sponsor_lock_seconds = 0;
+    faucet_config.total_shares = 0;
+    faucet_config.pool_generation = ctx.accounts.pool_counter.next_pool_generation;
+    faucet_config.drip_rate_per_second = 0;
+    faucet_config.drip_cap = 0;
+    faucet_config.cooldown_mode = CooldownMode::UnixTimestamp;
+    faucet_config.cooldown_slots = 0;
+    faucet_config.epoch_claim_limit = 0;
+    faucet_config.current_epoch = 0;
+    faucet_config.claims_this_epoch = 0;
+    faucet_config.operator = None;
+    faucet_config.operator_max_claim_amount = 0;
+    faucet_config.operator_min_cooldown_seconds = 0;
+    faucet_config.paused = false;
+    faucet_config.referral_bonus_amount = 0;
+    faucet_config.referral_cooldown_seconds = 0;
+    faucet_config.reserved = [0; CONFIG_RESERVED_BYTES];
+
+    ctx.accounts.pool_counter.bump = ctx.bumps.pool_counter;
 
     Ok(())
 }
@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{
+    CONFIG_RESERVED_BYTES, DISCRIMINATOR, FAUCET_CONFIG_SEEDS, FAUCET_CONFIG_VERSION, PAUSE_FAUCET,
+    PROGRAM_STATE_SEEDS,
+};
+use crate::errors::{FaucetError, GuardianError};
+use crate::states::{CooldownMode, FaucetConfig, LegacyFaucetConfig, ProgramState};
+use crate::utils::realloc_rent_exempt;
+
+#[derive(Accounts)]
+pub struct MigrateFaucetConfig<'info> {
+    #[account(
+      seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+      bump = program_state.bump,
+      constraint = !program_state.is_paused(PAUSE_FAUCET)? @ GuardianError::ModulePaused,
+   )]
+    pub program_state: Account<'info, ProgramState>,
+
+    /// CHECK: Deserialized by hand because the legacy layout no longer matches `FaucetConfig`.
+    #[account(
+      mut,
+      seeds = [FAUCET_CONFIG_SEEDS.as_bytes()],
+      bump,
+      owner = crate::ID,
+   )]
+    pub faucet_config: UncheckedAccount<'info>,
+
+    // Pays for the extra rent of the larger account.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Upgrades a legacy `FaucetConfig` in place to the current layout, keeping its settings
+/// and defaulting every newer field as `initialize_faucet` does. Deposit and claim totals
+/// start from zero because the legacy layout did not track them.
+pub fn migrate_config(ctx: Context<MigrateFaucetConfig>) -> Result<()> {
+    let faucet_config_info = ctx.accounts.faucet_config.to_account_info();
+    let legacy = {
+        let data = faucet_config_info.try_borrow_data()?;
+        require!(
+            data.len() == LegacyFaucetConfig::LEN && data.starts_with(FaucetConfig::DISCRIMINATOR),
+            FaucetError::UnsupportedConfigLayout
+        );
+        LegacyFaucetConfig::deserialize(&mut &data[DISCRIMINATOR..])?
+    };
+    require_keys_eq!(
+        legacy.owner,
+        ctx.accounts.owner.key(),
+        FaucetError::Unauthorized
+    );
+
+    let faucet_config = FaucetConfig {
+        version: FAUCET_CONFIG_VERSION,
+        owner: legacy.owner,
+        mint: legacy.mint,
+        allowed_claim_amount: legacy.allowed_claim_amount,
+        treasury_ata: legacy.treasury_ata,
+        cooldown_seconds: legacy.cooldown_seconds,
+        bump: legacy.bump,
+        sponsor_rent: false,
+        total_deposited: 0,
+        total_claimed: 0,
+        sponsored_pool: false,
+        sponsor_lock_seconds: 0,
+        total_shares: 0,
+        pool_generation: 0,
+        drip_rate_per_second: 0,
+        drip_cap: 0,
+        cooldown_mode: CooldownMode::UnixTimestamp,
+        cooldown_slots: 0,
+        epoch_claim_limit: 0,
+        current_epoch: 0,
+        claims_this_epoch: 0,
+        operator: None,
+        operator_max_claim_amount: 0,
+        operator_min_cooldown_seconds: 0,
+        paused: false,
+        referral_bonus_amount: 0,
+        referral_cooldown_seconds: 0,
+        reserved: [0; CONFIG_RESERVED_BYTES],
+    };
+
+    realloc_rent_exempt(
+        &faucet_config_info,
+        &ctx.accounts.owner.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        DISCRIMINATOR + FaucetConfig::INIT_SPACE,
+    )?;
+
+    let mut data = faucet_config_info.try_borrow_mut_data()?;
+    faucet_config.try_serialize(&mut &mut data[..])?;
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{
+    DISCRIMINATOR, FAUCET_RECIPIENT_SEEDS, FAUCET_RECIPIENT_VERSION, PAUSE_FAUCET,
+    PROGRAM_STATE_SEEDS, RECIPIENT_RESERVED_BYTES,
+};
+use crate::errors::{FaucetError, GuardianError};
+use crate::states::{FaucetRecipientData, LegacyFaucetRecipientData, ProgramState};
+use crate::utils::realloc_rent_exempt;
+
+#[derive(Accounts)]
+pub struct MigrateRecipientData<'info> {
+    #[account(
+      seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+      bump = program_state.bump,
+      constraint = !program_state.is_paused(PAUSE_FAUCET)? @ GuardianError::ModulePaused,
+   )]
+    pub program_state: Account<'info, ProgramState>,
+
+    /// CHECK: Deserialized by hand because the legacy layout no longer matches `FaucetRecipientData`.
+    #[account(
+      mut,
+      seeds = [FAUCET_RECIPIENT_SEEDS.as_bytes(), recipient.key().as_ref()],
+      bump,
+      owner = crate::ID,
+    )]
+    pub recipient_data: UncheckedAccount<'info>,
+
+    /// CHECK: Only used to derive the record address; migrating does not change what it records.
+    pub recipient: UncheckedAccount<'info>,
+
+    // Pays for the extra rent of the larger account; anyone may migrate a record.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Upgrades a legacy 16-byte recipient record in place so the recipient keeps their cooldown.
+/// The recipient paid for the legacy record, so it stays refundable to them on close.
+pub fn migrate_recipient_data(ctx: Context<MigrateRecipientData>) -> Result<()> {
+    let recipient_data_info = ctx.accounts.recipient_data.to_account_info();
+    let legacy = {
+        let data = recipient_data_info.try_borrow_data()?;
+        require!(
+            data.len() == LegacyFaucetRecipientData::LEN
+                && data.starts_with(FaucetRecipientData::DISCRIMINATOR),
+            FaucetError::UnsupportedRecordLayout
+        );
+        LegacyFaucetRecipientData::deserialize(&mut &data[DISCRIMINATOR..])?
+    };
+
+    let recipient_data = FaucetRecipientData {
+        version: FAUCET_RECIPIENT_VERSION,
+        last_claimed_at: legacy.last_claimed_at,
+        last_claimed_slot: 0,
+        referrer: None,
+        referral_count: 0,
+        last_referral_rewarded_at: 0,
+        rent_payer: ctx.accounts.recipient.key(),
+        reserved: [0; RECIPIENT_RESERVED_BYTES],
+    };
+
+    realloc_rent_exempt(
+        &recipient_data_info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        DISCRIMINATOR + FaucetRecipientData::INIT_SPACE,
+    )?;
+
+    let mut data = recipient_data_info.try_borrow_mut_data()?;
+    recipient_data.try_serialize(&mut &mut data[..])?;
+
+    Ok(())
+}
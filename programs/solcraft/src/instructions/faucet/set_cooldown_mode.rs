@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{FAUCET_CONFIG_SEEDS, PAUSE_FAUCET, PROGRAM_STATE_SEEDS};
+use crate::errors::{FaucetError, GuardianError};
+use crate::states::{CooldownMode, FaucetConfig, ProgramState};
+
+#[derive(Accounts)]
+#[instruction(mode: CooldownMode, cooldown: u64)]
+pub struct SetCooldownMode<'info> {
+    #[account(
+      seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+      bump = program_state.bump,
+      constraint = !program_state.is_paused(PAUSE_FAUCET)? @ GuardianError::ModulePaused,
+   )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+      mut,
+      seeds = [FAUCET_CONFIG_SEEDS.as_bytes()],
+      bump = faucet_config.bump,
+      constraint = faucet_config.is_owner_or_operator(&authority.key()) @ FaucetError::Unauthorized,
+   )]
+    pub faucet_config: Account<'info, FaucetConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+/// `cooldown` is in seconds for `UnixTimestamp` mode and in slots for `Slot` mode.
+pub fn set_cooldown_mode(
+    ctx: Context<SetCooldownMode>,
+    mode: CooldownMode,
+    cooldown: u64,
+) -> Result<()> {
+    let faucet_config = &mut ctx.accounts.faucet_config;
+    let authority = ctx.accounts.authority.key();
+
+    match mode {
+        CooldownMode::UnixTimestamp => {
+            faucet_config.check_operator_bounds(
+                &authority,
+                faucet_config.allowed_claim_amount,
+                cooldown,
+            )?;
+            faucet_config.cooldown_seconds = cooldown;
+        }
+        // Slot cooldowns cannot be compared with the operator's minimum in seconds.
+        CooldownMode::Slot => {
+            require_keys_eq!(authority, faucet_config.owner, FaucetError::Unauthorized);
+            faucet_config.cooldown_slots = cooldown;
+        }
+    }
+    faucet_config.cooldown_mode = mode;
+
+    Ok(())
+}
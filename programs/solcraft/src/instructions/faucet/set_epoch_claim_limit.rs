@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{FAUCET_CONFIG_SEEDS, PAUSE_FAUCET, PROGRAM_STATE_SEEDS};
+use crate::errors::{FaucetError, GuardianError};
+use crate::states::{FaucetConfig, ProgramState};
+
+#[derive(Accounts)]
+#[instruction(epoch_claim_limit: u64)]
+pub struct SetEpochClaimLimit<'info> {
+    #[account(
+      seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+      bump = program_state.bump,
+      constraint = !program_state.is_paused(PAUSE_FAUCET)? @ GuardianError::ModulePaused,
+   )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+      mut,
+      seeds = [FAUCET_CONFIG_SEEDS.as_bytes()],
+      bump = faucet_config.bump,
+      constraint = faucet_config.is_owner_or_operator(&authority.key()) @ FaucetError::Unauthorized,
+   )]
+    pub faucet_config: Account<'info, FaucetConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+/// A limit of zero removes the per-epoch cap; only the owner can raise or remove it.
+pub fn set_epoch_claim_limit(
+    ctx: Context<SetEpochClaimLimit>,
+    epoch_claim_limit: u64,
+) -> Result<()> {
+    let faucet_config = &mut ctx.accounts.faucet_config;
+    faucet_config
+        .check_operator_epoch_claim_limit(&ctx.accounts.authority.key(), epoch_claim_limit)?;
+    faucet_config.epoch_claim_limit = epoch_claim_limit;
+
+    Ok(())
+}
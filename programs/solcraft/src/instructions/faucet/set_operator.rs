@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{FAUCET_CONFIG_SEEDS, PAUSE_FAUCET, PROGRAM_STATE_SEEDS};
+use crate::errors::{FaucetError, GuardianError};
+use crate::states::{FaucetConfig, ProgramState};
+
+#[derive(Accounts)]
+#[instruction(operator: Option<Pubkey>, max_claim_amount: u64, min_cooldown_seconds: u64)]
+pub struct SetFaucetOperator<'info> {
+    #[account(
+      seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+      bump = program_state.bump,
+      constraint = !program_state.is_paused(PAUSE_FAUCET)? @ GuardianError::ModulePaused,
+   )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+      mut,
+      seeds = [FAUCET_CONFIG_SEEDS.as_bytes()],
+      bump = faucet_config.bump,
+      has_one = owner @ FaucetError::Unauthorized,
+   )]
+    pub faucet_config: Account<'info, FaucetConfig>,
+
+    pub owner: Signer<'info>,
+}
+
+/// Passing `None` clears the operator. The limits bound every claim setting the operator changes.
+pub fn set_operator(
+    ctx: Context<SetFaucetOperator>,
+    operator: Option<Pubkey>,
+    max_claim_amount: u64,
+    min_cooldown_seconds: u64,
+) -> Result<()> {
+    let faucet_config = &mut ctx.accounts.faucet_config;
+    faucet_config.operator = operator;
+    faucet_config.operator_max_claim_amount = max_claim_amount;
+    faucet_config.operator_min_cooldown_seconds = min_cooldown_seconds;
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{FAUCET_CONFIG_SEEDS, PAUSE_FAUCET, PROGRAM_STATE_SEEDS};
+use crate::errors::{FaucetError, GuardianError};
+use crate::states::{FaucetConfig, ProgramState};
+
+#[derive(Accounts)]
+#[instruction(enabled: bool)]
+pub struct SetRentSponsorship<'info> {
+    #[account(
+      seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+      bump = program_state.bump,
+      constraint = !program_state.is_paused(PAUSE_FAUCET)? @ GuardianError::ModulePaused,
+   )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+      mut,
+      seeds = [FAUCET_CONFIG_SEEDS.as_bytes()],
+      bump = faucet_config.bump,
+      has_one = owner @ FaucetError::Unauthorized,
+   )]
+    pub faucet_config: Account<'info, FaucetConfig>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn set_rent_sponsorship(ctx: Context<SetRentSponsorship>, enabled: bool) -> Result<()> {
+    let faucet_config = &mut ctx.accounts.faucet_config;
+    faucet_config.sponsor_rent = enabled;
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::constants::{
+    FAUCET_CONFIG_SEEDS, MAX_SPONSOR_LOCK_SECONDS, PAUSE_FAUCET, PROGRAM_STATE_SEEDS,
+};
+use crate::errors::{FaucetError, GuardianError};
+use crate::states::{FaucetConfig, ProgramState};
+
+#[derive(Accounts)]
+#[instruction(enabled: bool, lock_seconds: u64)]
+pub struct SetSponsoredPool<'info> {
+    #[account(
+      seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+      bump = program_state.bump,
+      constraint = !program_state.is_paused(PAUSE_FAUCET)? @ GuardianError::ModulePaused,
+   )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+      mut,
+      seeds = [FAUCET_CONFIG_SEEDS.as_bytes()],
+      bump = faucet_config.bump,
+      has_one = owner @ FaucetError::Unauthorized,
+   )]
+    pub faucet_config: Account<'info, FaucetConfig>,
+
+    #[account(
+        address = faucet_config.treasury_ata @ FaucetError::InvalidTreasuryAta,
+    )]
+    pub treasury_ata: InterfaceAccount<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+}
+
+/// The sponsor lock can only change while no sponsor holds shares, so deposits are never
+/// locked for longer than they were made under.
+pub fn set_sponsored_pool(
+    ctx: Context<SetSponsoredPool>,
+    enabled: bool,
+    lock_seconds: u64,
+) -> Result<()> {
+    let faucet_config = &mut ctx.accounts.faucet_config;
+    let treasury_balance = ctx.accounts.treasury_ata.amount;
+    require!(
+        lock_seconds <= MAX_SPONSOR_LOCK_SECONDS,
+        FaucetError::InvalidSponsorLock
+    );
+
+    if enabled && !faucet_config.sponsored_pool {
+        // Existing owner funds would otherwise be handed to the first sponsor's shares.
+        require!(treasury_balance == 0, FaucetError::PoolNotEmpty);
+        faucet_config.total_shares = 0;
+    }
+
+    if !enabled && faucet_config.sponsored_pool {
+        // Leaving pool mode hands the treasury back to the owner, so sponsors must exit first.
+        require!(
+            faucet_config.total_shares == 0 || treasury_balance == 0,
+            FaucetError::PoolNotEmpty
+        );
+        faucet_config.total_shares = 0;
+        faucet_config.pool_generation += 1;
+    }
+
+    require!(
+        lock_seconds == faucet_config.sponsor_lock_seconds || faucet_config.total_shares == 0,
+        FaucetError::InvalidSponsorLock
+    );
+
+    faucet_config.sponsored_pool = enabled;
+    faucet_config.sponsor_lock_seconds = lock_seconds;
+
+    Ok(())
+}
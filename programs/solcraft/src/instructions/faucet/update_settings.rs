@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{FAUCET_CONFIG_SEEDS, PAUSE_FAUCET, PROGRAM_STATE_SEEDS};
+use crate::errors::{FaucetError, GuardianError};
+use crate::states::{FaucetConfig, ProgramState};
+
+#[derive(Accounts)]
+#[instruction(allowed_claim_amount: u64, cooldown_seconds: u64)]
+pub struct UpdateFaucetSettings<'info> {
+    #[account(
+      seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+      bump = program_state.bump,
+      constraint = !program_state.is_paused(PAUSE_FAUCET)? @ GuardianError::ModulePaused,
+   )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+      mut,
+      seeds = [FAUCET_CONFIG_SEEDS.as_bytes()],
+      bump = faucet_config.bump,
+      constraint = faucet_config.is_owner_or_operator(&authority.key()) @ FaucetError::Unauthorized,
+   )]
+    pub faucet_config: Account<'info, FaucetConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn update_settings(
+    ctx: Context<UpdateFaucetSettings>,
+    allowed_claim_amount: u64,
+    cooldown_seconds: u64,
+) -> Result<()> {
+    let faucet_config = &mut ctx.accounts.faucet_config;
+    faucet_config.check_operator_bounds(
+        &ctx.accounts.authority.key(),
+        allowed_claim_amount,
+        cooldown_seconds,
+    )?;
+
+    faucet_config.allowed_claim_amount = allowed_claim_amount;
+    faucet_config.cooldown_seconds = cooldown_seconds;
+
+    Ok(())
+}
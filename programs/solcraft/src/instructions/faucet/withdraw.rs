@@ -1,62 +1,104 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{transfer, Transfer};
-use anchor_spl::token_interface::{TokenAccount, TokenInterface};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
-use crate::constants::FAUCET_CONFIG_SEEDS;
-use crate::errors::FaucetError;
-use crate::states::FaucetConfig;
+use crate::constants::{FAUCET_CONFIG_SEEDS, PAUSE_FAUCET, PROGRAM_STATE_SEEDS};
+use crate::errors::{FaucetError, GuardianError};
+use crate::states::{FaucetConfig, ProgramState};
+use crate::utils::transfer_checked_with_hooks;
 
 #[derive(Accounts)]
-#[instruction(amount: u64)]
 pub struct Withdraw<'info> {
+    #[account(
+      seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+      bump = program_state.bump,
+      constraint = !program_state.is_paused(PAUSE_FAUCET)? @ GuardianError::ModulePaused,
+   )]
+    pub program_state: Account<'info, ProgramState>,
+
     #[account(
       mut,
       seeds = [FAUCET_CONFIG_SEEDS.as_bytes()],
       bump = faucet_config.bump,
-      constraint = recipient.key() == faucet_config.owner @ FaucetError::Unauthorized,
+      has_one = owner @ FaucetError::Unauthorized,
    )]
     pub faucet_config: Account<'info, FaucetConfig>,
 
+    #[account(address = faucet_config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
     #[account(
         mut,
         associated_token::mint = faucet_config.mint,
         associated_token::authority = faucet_config,
+        associated_token::token_program = token_program,
         constraint = faucet_config.treasury_ata == treasury_ata.key() @ FaucetError::InvalidTreasuryAta,
     )]
     pub treasury_ata: InterfaceAccount<'info, TokenAccount>,
 
+    /// CHECK: Any wallet may receive the withdrawal; it is only used as the ATA authority.
+    pub destination: UncheckedAccount<'info>,
+
     #[account(
-        mut,
-        associated_token::mint = faucet_config.mint,
-        associated_token::authority = recipient,
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = mint,
+        associated_token::authority = destination,
+        associated_token::token_program = token_program,
     )]
-    pub recipient_ata: InterfaceAccount<'info, TokenAccount>,
+    pub destination_ata: InterfaceAccount<'info, TokenAccount>,
 
-    pub recipient: Signer<'info>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
 
     pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
 }
 
-pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
-    let treasury_balance = ctx.accounts.treasury_ata.amount.clone();
+pub fn withdraw<'info>(
+    ctx: Context<'_, '_, 'info, 'info, Withdraw<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let treasury_balance = ctx.accounts.treasury_ata.amount;
     require!(treasury_balance >= amount, FaucetError::InsufficientFunds);
 
+    transfer_from_treasury(ctx, amount)
+}
+
+/// Withdraws the entire treasury balance without the caller needing to know it.
+pub fn withdraw_all<'info>(ctx: Context<'_, '_, 'info, 'info, Withdraw<'info>>) -> Result<()> {
+    let treasury_balance = ctx.accounts.treasury_ata.amount;
+    require!(treasury_balance > 0, FaucetError::InsufficientFunds);
+
+    transfer_from_treasury(ctx, treasury_balance)
+}
+
+fn transfer_from_treasury<'info>(
+    ctx: Context<'_, '_, 'info, 'info, Withdraw<'info>>,
+    amount: u64,
+) -> Result<()> {
+    // In sponsored pool mode the treasury belongs to the sponsors, not the owner.
+    require!(
+        !ctx.accounts.faucet_config.sponsored_pool,
+        FaucetError::SponsoredPoolActive
+    );
+
     let seeds = &[
         FAUCET_CONFIG_SEEDS.as_bytes(),
         &[ctx.accounts.faucet_config.bump],
     ];
 
-    transfer(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.treasury_ata.to_account_info(),
-                to: ctx.accounts.recipient_ata.to_account_info(),
-                authority: ctx.accounts.faucet_config.to_account_info(),
-            },
-            &[seeds],
-        ),
+    transfer_checked_with_hooks(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.treasury_ata.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.destination_ata.to_account_info(),
+        &ctx.accounts.faucet_config.to_account_info(),
+        ctx.remaining_accounts,
         amount,
+        ctx.accounts.mint.decimals,
+        &[seeds],
     )?;
 
     Ok(())
@@ -0,0 +1,64 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::constants::{
+    FAUCET_CONFIG_SEEDS, FAUCET_RENT_VAULT_SEEDS, PAUSE_FAUCET, PROGRAM_STATE_SEEDS,
+};
+use crate::errors::{FaucetError, GuardianError};
+use crate::states::{FaucetConfig, ProgramState};
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct WithdrawRentVault<'info> {
+    #[account(
+      seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+      bump = program_state.bump,
+      constraint = !program_state.is_paused(PAUSE_FAUCET)? @ GuardianError::ModulePaused,
+   )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+      seeds = [FAUCET_CONFIG_SEEDS.as_bytes()],
+      bump = faucet_config.bump,
+      has_one = owner @ FaucetError::Unauthorized,
+   )]
+    pub faucet_config: Account<'info, FaucetConfig>,
+
+    #[account(
+        mut,
+        seeds = [FAUCET_RENT_VAULT_SEEDS.as_bytes()],
+        bump,
+    )]
+    pub rent_vault: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn withdraw_rent_vault(ctx: Context<WithdrawRentVault>, amount: u64) -> Result<()> {
+    let vault_info = ctx.accounts.rent_vault.to_account_info();
+
+    // The vault must stay rent-exempt after the withdrawal.
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(vault_info.data_len());
+    let withdrawable_amount = vault_info.lamports().saturating_sub(rent_exempt_minimum);
+    require!(
+        withdrawable_amount >= amount,
+        FaucetError::InsufficientFunds
+    );
+
+    let seeds = &[FAUCET_RENT_VAULT_SEEDS.as_bytes(), &[ctx.bumps.rent_vault]];
+
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.rent_vault.to_account_info(),
+                to: ctx.accounts.owner.to_account_info(),
+            },
+            &[seeds],
+        ),
+        amount,
+    )?;
+
+    Ok(())
+}
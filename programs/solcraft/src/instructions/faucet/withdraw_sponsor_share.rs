@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::{
+    FAUCET_CONFIG_SEEDS, FAUCET_DEPOSITOR_SEEDS, PAUSE_FAUCET, PROGRAM_STATE_SEEDS,
+};
+use crate::errors::{FaucetError, GuardianError};
+use crate::states::{FaucetConfig, FaucetDepositorRecord, ProgramState};
+use crate::utils::transfer_checked_with_hooks;
+
+#[derive(Accounts)]
+pub struct WithdrawSponsorShare<'info> {
+    #[account(
+      seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+      bump = program_state.bump,
+      constraint = !program_state.is_paused(PAUSE_FAUCET)? @ GuardianError::ModulePaused,
+   )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+      mut,
+      seeds = [FAUCET_CONFIG_SEEDS.as_bytes()],
+      bump = faucet_config.bump,
+      constraint = faucet_config.sponsored_pool @ FaucetError::SponsoredPoolDisabled,
+   )]
+    pub faucet_config: Account<'info, FaucetConfig>,
+
+    #[account(address = faucet_config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = faucet_config.mint,
+        associated_token::authority = faucet_config,
+        associated_token::token_program = token_program,
+        constraint = faucet_config.treasury_ata == treasury_ata.key() @ FaucetError::InvalidTreasuryAta,
+    )]
+    pub treasury_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+          FAUCET_DEPOSITOR_SEEDS.as_bytes(),
+          faucet_config.key().as_ref(),
+          depositor.key().as_ref(),
+        ],
+        bump = depositor_record.bump,
+        has_one = depositor @ FaucetError::Unauthorized,
+    )]
+    pub depositor_record: Account<'info, FaucetDepositorRecord>,
+
+    #[account(
+        mut,
+        associated_token::mint = faucet_config.mint,
+        associated_token::authority = depositor,
+        associated_token::token_program = token_program,
+    )]
+    pub depositor_ata: InterfaceAccount<'info, TokenAccount>,
+
+    pub depositor: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Redeems all of the depositor's pool shares for their pro-rata part of the remaining treasury.
+pub fn withdraw_sponsor_share<'info>(
+    ctx: Context<'_, '_, 'info, 'info, WithdrawSponsorShare<'info>>,
+) -> Result<()> {
+    let faucet_config = &ctx.accounts.faucet_config;
+    let depositor_record = &ctx.accounts.depositor_record;
+
+    let shares = depositor_record.active_shares(faucet_config);
+    require!(shares > 0, FaucetError::NoSponsorShares);
+
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    let unlocks_at = depositor_record.last_deposited_at.saturating_add(
+        i64::try_from(faucet_config.sponsor_lock_seconds).map_err(|_| FaucetError::MathOverflow)?,
+    );
+    require!(
+        current_timestamp >= unlocks_at,
+        FaucetError::SponsorLockActive
+    );
+
+    let treasury_balance = ctx.accounts.treasury_ata.amount;
+    let amount = faucet_config.amount_for_shares(shares, treasury_balance)?;
+
+    if amount > 0 {
+        let seeds = &[FAUCET_CONFIG_SEEDS.as_bytes(), &[faucet_config.bump]];
+
+        transfer_checked_with_hooks(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.treasury_ata.to_account_info(),
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.depositor_ata.to_account_info(),
+            &faucet_config.to_account_info(),
+            ctx.remaining_accounts,
+            amount,
+            ctx.accounts.mint.decimals,
+            &[seeds],
+        )?;
+    }
+
+    let faucet_config = &mut ctx.accounts.faucet_config;
+    faucet_config.total_shares = faucet_config
+        .total_shares
+        .checked_sub(shares)
+        .ok_or(FaucetError::MathOverflow)?;
+    ctx.accounts.depositor_record.shares = 0;
+
+    Ok(())
+}
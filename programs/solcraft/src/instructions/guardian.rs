@@ -0,0 +1,9 @@
+pub mod initialize;
+pub mod pause;
+pub mod set_guardian;
+pub mod unpause;
+
+pub use initialize::*;
+pub use pause::*;
+pub use set_guardian::*;
+pub use unpause::*;
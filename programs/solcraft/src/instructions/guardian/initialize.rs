@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{DISCRIMINATOR, PROGRAM_STATE_SEEDS};
+use crate::errors::GuardianError;
+use crate::program::Solcraft;
+use crate::states::ProgramState;
+
+#[derive(Accounts)]
+#[instruction(guardian: Pubkey, max_pause_seconds: u64)]
+pub struct InitializeProgramState<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = DISCRIMINATOR + ProgramState::INIT_SPACE,
+        seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+        bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()) @ GuardianError::Unauthorized,
+    )]
+    pub program: Program<'info, Solcraft>,
+
+    // Only the program's upgrade authority can create the program state.
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key()) @ GuardianError::Unauthorized,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_program_state(
+    ctx: Context<InitializeProgramState>,
+    guardian: Pubkey,
+    max_pause_seconds: u64,
+) -> Result<()> {
+    require!(max_pause_seconds > 0, GuardianError::InvalidPauseDuration);
+
+    let program_state = &mut ctx.accounts.program_state;
+    program_state.authority = ctx.accounts.authority.key();
+    program_state.guardian = guardian;
+    program_state.paused_modules = 0;
+    program_state.pause_expires_at = 0;
+    program_state.max_pause_seconds = max_pause_seconds;
+    program_state.bump = ctx.bumps.program_state;
+
+    Ok(())
+}
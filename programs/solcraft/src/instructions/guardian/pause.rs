@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::PROGRAM_STATE_SEEDS;
+use crate::errors::GuardianError;
+use crate::states::ProgramState;
+
+#[derive(Accounts)]
+#[instruction(modules: u8)]
+pub struct GuardianPause<'info> {
+    #[account(
+        mut,
+        seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+        bump = program_state.bump,
+        has_one = guardian @ GuardianError::Unauthorized,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    pub guardian: Signer<'info>,
+}
+
+/// Pauses the given `PAUSE_*` modules until `max_pause_seconds` from now. Pausing again
+/// restarts the deadline for every paused module.
+pub fn guardian_pause(ctx: Context<GuardianPause>, modules: u8) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let program_state = &mut ctx.accounts.program_state;
+
+    // Flags left over from a lapsed pause must not be revived by a new one.
+    if now >= program_state.pause_expires_at {
+        program_state.paused_modules = 0;
+    }
+
+    program_state.paused_modules |= modules;
+    program_state.pause_expires_at = now.saturating_add(program_state.max_pause_seconds as i64);
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::PROGRAM_STATE_SEEDS;
+use crate::errors::GuardianError;
+use crate::states::ProgramState;
+
+#[derive(Accounts)]
+#[instruction(guardian: Pubkey, max_pause_seconds: u64)]
+pub struct SetGuardian<'info> {
+    #[account(
+        mut,
+        seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+        bump = program_state.bump,
+        has_one = authority @ GuardianError::Unauthorized,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn set_guardian(
+    ctx: Context<SetGuardian>,
+    guardian: Pubkey,
+    max_pause_seconds: u64,
+) -> Result<()> {
+    require!(max_pause_seconds > 0, GuardianError::InvalidPauseDuration);
+
+    let program_state = &mut ctx.accounts.program_state;
+    program_state.guardian = guardian;
+    program_state.max_pause_seconds = max_pause_seconds;
+
+    Ok(())
+}
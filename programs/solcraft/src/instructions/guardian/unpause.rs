@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::PROGRAM_STATE_SEEDS;
+use crate::errors::GuardianError;
+use crate::states::ProgramState;
+
+#[derive(Accounts)]
+#[instruction(modules: u8)]
+pub struct GuardianUnpause<'info> {
+    #[account(
+        mut,
+        seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+        bump = program_state.bump,
+        constraint = signer.key() == program_state.guardian
+            || signer.key() == program_state.authority @ GuardianError::Unauthorized,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    pub signer: Signer<'info>,
+}
+
+/// Either the guardian or the program state authority can lift a pause early.
+pub fn guardian_unpause(ctx: Context<GuardianUnpause>, modules: u8) -> Result<()> {
+    let program_state = &mut ctx.accounts.program_state;
+    program_state.paused_modules &= !modules;
+
+    Ok(())
+}
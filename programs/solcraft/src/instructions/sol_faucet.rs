@@ -0,0 +1,9 @@
+pub mod claim;
+pub mod deposit;
+pub mod initialize;
+pub mod withdraw;
+
+pub use claim::*;
+pub use deposit::*;
+pub use initialize::*;
+pub use withdraw::*;
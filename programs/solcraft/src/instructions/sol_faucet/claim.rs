@@ -0,0 +1,93 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::constants::{
+    DISCRIMINATOR, PAUSE_SOL_FAUCET, PROGRAM_STATE_SEEDS, SOL_FAUCET_CONFIG_SEEDS,
+    SOL_FAUCET_RECIPIENT_SEEDS, SOL_FAUCET_VAULT_SEEDS,
+};
+use crate::errors::{FaucetError, GuardianError};
+use crate::states::{FaucetRecipientData, ProgramState, SolFaucetConfig};
+
+#[derive(Accounts)]
+pub struct ClaimSol<'info> {
+    #[account(
+      seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+      bump = program_state.bump,
+      constraint = !program_state.is_paused(PAUSE_SOL_FAUCET)? @ GuardianError::ModulePaused,
+   )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+      seeds = [SOL_FAUCET_CONFIG_SEEDS.as_bytes()],
+      bump = sol_faucet_config.bump,
+   )]
+    pub sol_faucet_config: Account<'info, SolFaucetConfig>,
+
+    #[account(
+      init_if_needed,
+      payer = recipient,
+      space = DISCRIMINATOR + FaucetRecipientData::INIT_SPACE,
+      seeds = [SOL_FAUCET_RECIPIENT_SEEDS.as_bytes(), recipient.key().as_ref()],
+      bump,
+    )]
+    pub recipient_data: Account<'info, FaucetRecipientData>,
+
+    #[account(
+        mut,
+        seeds = [SOL_FAUCET_VAULT_SEEDS.as_bytes()],
+        bump = sol_faucet_config.vault_bump,
+        address = sol_faucet_config.vault,
+    )]
+    pub vault: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn claim_sol(ctx: Context<ClaimSol>) -> Result<()> {
+    let sol_faucet_config = &ctx.accounts.sol_faucet_config;
+    let claim_amount = sol_faucet_config.allowed_claim_lamports;
+
+    // Claims may never dip into the vault's rent-exempt reserve.
+    let vault_info = ctx.accounts.vault.to_account_info();
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(vault_info.data_len());
+    let available_amount = vault_info.lamports().saturating_sub(rent_exempt_minimum);
+    require!(
+        available_amount >= claim_amount,
+        FaucetError::InsufficientFunds
+    );
+
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    require!(
+        ctx.accounts
+            .recipient_data
+            .cooldown_elapsed(sol_faucet_config.cooldown_seconds, current_timestamp),
+        FaucetError::CooldownNotElapsed
+    );
+
+    let seeds = &[
+        SOL_FAUCET_VAULT_SEEDS.as_bytes(),
+        &[sol_faucet_config.vault_bump],
+    ];
+
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.recipient.to_account_info(),
+            },
+            &[seeds],
+        ),
+        claim_amount,
+    )?;
+
+    let recipient_data = &mut ctx.accounts.recipient_data;
+    if recipient_data.last_claimed_at == 0 {
+        recipient_data.initialize(ctx.accounts.recipient.key());
+    }
+    recipient_data.last_claimed_at = current_timestamp;
+
+    Ok(())
+}
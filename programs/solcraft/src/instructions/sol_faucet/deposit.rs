@@ -0,0 +1,55 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::constants::{
+    PAUSE_SOL_FAUCET, PROGRAM_STATE_SEEDS, SOL_FAUCET_CONFIG_SEEDS, SOL_FAUCET_VAULT_SEEDS,
+};
+use crate::errors::{FaucetError, GuardianError};
+use crate::states::{ProgramState, SolFaucetConfig};
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct DepositSol<'info> {
+    #[account(
+      seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+      bump = program_state.bump,
+      constraint = !program_state.is_paused(PAUSE_SOL_FAUCET)? @ GuardianError::ModulePaused,
+   )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+      seeds = [SOL_FAUCET_CONFIG_SEEDS.as_bytes()],
+      bump = sol_faucet_config.bump,
+   )]
+    pub sol_faucet_config: Account<'info, SolFaucetConfig>,
+
+    #[account(
+        mut,
+        seeds = [SOL_FAUCET_VAULT_SEEDS.as_bytes()],
+        bump = sol_faucet_config.vault_bump,
+        address = sol_faucet_config.vault,
+    )]
+    pub vault: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn deposit_sol(ctx: Context<DepositSol>, amount: u64) -> Result<()> {
+    let depositor_balance = ctx.accounts.depositor.lamports();
+    require!(depositor_balance >= amount, FaucetError::InsufficientFunds);
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.depositor.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    Ok(())
+}
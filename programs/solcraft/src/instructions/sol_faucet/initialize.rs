@@ -0,0 +1,56 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::constants::{
+    DISCRIMINATOR, PAUSE_SOL_FAUCET, PROGRAM_STATE_SEEDS, SOL_FAUCET_CONFIG_SEEDS,
+    SOL_FAUCET_VAULT_SEEDS,
+};
+use crate::errors::GuardianError;
+use crate::states::{ProgramState, SolFaucetConfig};
+
+#[derive(Accounts)]
+pub struct InitializeSolFaucet<'info> {
+    #[account(
+      seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+      bump = program_state.bump,
+      constraint = !program_state.is_paused(PAUSE_SOL_FAUCET)? @ GuardianError::ModulePaused,
+   )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+      init,
+      payer = owner,
+      space = DISCRIMINATOR + SolFaucetConfig::INIT_SPACE,
+      seeds = [SOL_FAUCET_CONFIG_SEEDS.as_bytes()],
+      bump,
+   )]
+    pub sol_faucet_config: Account<'info, SolFaucetConfig>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 0,
+        seeds = [SOL_FAUCET_VAULT_SEEDS.as_bytes()],
+        bump,
+        owner = system_program::ID
+    )]
+    /// CHECK: This is a system account to hold the lamports dispensed by the faucet
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_sol_faucet(ctx: Context<InitializeSolFaucet>) -> Result<()> {
+    let sol_faucet_config = &mut ctx.accounts.sol_faucet_config;
+
+    sol_faucet_config.owner = ctx.accounts.owner.key();
+    sol_faucet_config.vault = ctx.accounts.vault.key();
+    sol_faucet_config.cooldown_seconds = 3600; // default 1 hour
+    sol_faucet_config.allowed_claim_lamports = 1_000_000_000; // default 1 SOL
+    sol_faucet_config.bump = ctx.bumps.sol_faucet_config;
+    sol_faucet_config.vault_bump = ctx.bumps.vault;
+
+    Ok(())
+}
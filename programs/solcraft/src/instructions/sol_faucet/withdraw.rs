@@ -0,0 +1,69 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::constants::{
+    PAUSE_SOL_FAUCET, PROGRAM_STATE_SEEDS, SOL_FAUCET_CONFIG_SEEDS, SOL_FAUCET_VAULT_SEEDS,
+};
+use crate::errors::{FaucetError, GuardianError};
+use crate::states::{ProgramState, SolFaucetConfig};
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct WithdrawSol<'info> {
+    #[account(
+      seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+      bump = program_state.bump,
+      constraint = !program_state.is_paused(PAUSE_SOL_FAUCET)? @ GuardianError::ModulePaused,
+   )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+      seeds = [SOL_FAUCET_CONFIG_SEEDS.as_bytes()],
+      bump = sol_faucet_config.bump,
+      constraint = recipient.key() == sol_faucet_config.owner @ FaucetError::Unauthorized,
+   )]
+    pub sol_faucet_config: Account<'info, SolFaucetConfig>,
+
+    #[account(
+        mut,
+        seeds = [SOL_FAUCET_VAULT_SEEDS.as_bytes()],
+        bump = sol_faucet_config.vault_bump,
+        address = sol_faucet_config.vault,
+    )]
+    pub vault: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn withdraw_sol(ctx: Context<WithdrawSol>, amount: u64) -> Result<()> {
+    let vault_info = ctx.accounts.vault.to_account_info();
+
+    // Keep the vault rent-exempt after the withdrawal.
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(vault_info.data_len());
+    let withdrawable_amount = vault_info.lamports().saturating_sub(rent_exempt_minimum);
+    require!(
+        withdrawable_amount >= amount,
+        FaucetError::InsufficientFunds
+    );
+
+    let seeds = &[
+        SOL_FAUCET_VAULT_SEEDS.as_bytes(),
+        &[ctx.accounts.sol_faucet_config.vault_bump],
+    ];
+
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.recipient.to_account_info(),
+            },
+            &[seeds],
+        ),
+        amount,
+    )?;
+
+    Ok(())
+}
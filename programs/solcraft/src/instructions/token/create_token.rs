@@ -11,13 +11,29 @@ use anchor_spl::{
     token_interface::{mint_to, Mint, MintTo, TokenAccount, TokenInterface},
 };
 
-use crate::constants::{FACTORY_CONFIG_SEEDS, FACTORY_TREASURY, MAX_DECIMALS};
-use crate::errors::{FactoryError, TokenError};
-use crate::states::FactoryConfig;
+use anchor_spl::token_2022::spl_token_2022;
+
+use crate::constants::{
+    CREATOR_STATS_SEEDS, DISCRIMINATOR, FACTORY_CONFIG_SEEDS, FACTORY_TREASURY, FEE_DISCOUNT_SEEDS,
+    FEE_MINT_CONFIG_SEEDS, MAX_BPS, MAX_DECIMALS, PAUSE_TOKEN, PROGRAM_STATE_SEEDS,
+};
+use crate::errors::{FactoryError, GuardianError, TokenError};
+use crate::events::CreationFeeReferral;
+use crate::states::{
+    apply_discount, CreatorStats, FactoryConfig, FeeDiscount, FeeMintConfig, ProgramState,
+};
+use crate::utils::{gross_transfer_amount, transfer_checked_with_hooks};
 
 #[derive(Accounts)]
-#[instruction(name:String, symbol:String, uri:String, decimals:u8, supply:u64)]
+#[instruction(name:String, symbol:String, uri:String, decimals:u8, supply:u64, max_fee_lamports:u64, max_fee_amount:u64)]
 pub struct CreateToken<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PAUSE_TOKEN)? @ GuardianError::ModulePaused,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
     #[account(
         seeds = [FACTORY_CONFIG_SEEDS.as_bytes()],
         bump = factory_config.bump,
@@ -62,6 +78,44 @@ pub struct CreateToken<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = DISCRIMINATOR + CreatorStats::INIT_SPACE,
+        seeds = [CREATOR_STATS_SEEDS.as_bytes(), payer.key().as_ref()],
+        bump,
+    )]
+    pub creator_stats: Account<'info, CreatorStats>,
+
+    /// CHECK: Optional integrator wallet that receives `referral_fee_bps` of the lamport fee.
+    #[account(mut)]
+    pub referrer: Option<UncheckedAccount<'info>>,
+
+    // Partner discount for the payer, if the admin has granted one.
+    #[account(
+        seeds = [FEE_DISCOUNT_SEEDS.as_bytes(), payer.key().as_ref()],
+        bump = fee_discount.bump,
+    )]
+    pub fee_discount: Option<Account<'info, FeeDiscount>>,
+
+    // Pass the fee mint accounts below to pay the creation fee in SPL tokens instead of SOL.
+    #[account(
+        seeds = [FEE_MINT_CONFIG_SEEDS.as_bytes(), fee_mint_config.mint.as_ref()],
+        bump = fee_mint_config.bump,
+        constraint = fee_mint_config.enabled @ FactoryError::FeeMintDisabled,
+    )]
+    pub fee_mint_config: Option<Account<'info, FeeMintConfig>>,
+
+    pub fee_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub payer_fee_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub treasury_fee_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub fee_token_program: Option<Interface<'info, TokenInterface>>,
+
     pub token_program: Interface<'info, TokenInterface>,
     pub token_metadata_program: Program<'info, Metadata>,
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -69,25 +123,49 @@ pub struct CreateToken<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
-pub fn create_token(
-    ctx: Context<CreateToken>,
+/// Fails if the lamport creation fee, after surcharges and discounts, exceeds `max_fee_lamports`,
+/// or if the SPL fee price, after discounts, exceeds `max_fee_amount` base units of the fee mint.
+/// When paying in SPL tokens, any remaining accounts are forwarded to the fee mint's
+/// token program as transfer-hook extra accounts.
+#[allow(clippy::too_many_arguments)]
+pub fn create_token<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateToken<'info>>,
     name: String,
     symbol: String,
     uri: String,
     decimals: u8,
     supply: u64,
+    max_fee_lamports: u64,
+    max_fee_amount: u64,
 ) -> Result<()> {
     require!(name.len() <= 32, TokenError::InvalidInputStringLength);
     require!(decimals <= MAX_DECIMALS, TokenError::ExceedsMaxDecimals);
     require!(symbol.len() <= 10, TokenError::InvalidInputStringLength);
     require!(uri.len() <= 200, TokenError::InvalidInputStringLength);
 
+    let factory_config = &ctx.accounts.factory_config;
+    let partner_discount_bps = ctx
+        .accounts
+        .fee_discount
+        .as_ref()
+        .map_or(0, |fee_discount| fee_discount.discount_bps);
+    let discount_bps =
+        factory_config.discount_bps(&ctx.accounts.creator_stats, partner_discount_bps);
+    let is_token_2022 = ctx.accounts.token_program.key() == spl_token_2022::ID;
+    let creation_fee = factory_config.creation_fee(is_token_2022, discount_bps);
+
     // check the balance of payer to ensure they can pay creation fee
-    let payer_lamports = ctx.accounts.payer.to_account_info().lamports();
-    require!(
-        payer_lamports >= ctx.accounts.factory_config.creation_fee_lamports,
-        FactoryError::InsufficientCreationFee
-    );
+    if ctx.accounts.fee_mint_config.is_none() {
+        require!(
+            creation_fee <= max_fee_lamports,
+            FactoryError::FeeExceedsMax
+        );
+        let payer_lamports = ctx.accounts.payer.to_account_info().lamports();
+        require!(
+            payer_lamports >= creation_fee,
+            FactoryError::InsufficientCreationFee
+        );
+    }
 
     // Mint to payer's associated token account
     let mint_accounts = MintTo {
@@ -148,15 +226,135 @@ pub fn create_token(
         },
     ))?;
 
+    let creator_stats = &mut ctx.accounts.creator_stats;
+    creator_stats.creator = ctx.accounts.payer.key();
+    creator_stats.bump = ctx.bumps.creator_stats;
+    creator_stats.tokens_created = creator_stats.tokens_created.saturating_add(1);
+
+    if ctx.accounts.fee_mint_config.is_some() {
+        return pay_fee_in_tokens(&ctx, discount_bps, max_fee_amount);
+    }
+
+    creator_stats.total_fee_lamports_paid = creator_stats
+        .total_fee_lamports_paid
+        .saturating_add(creation_fee);
+
+    if creation_fee == 0 {
+        return Ok(());
+    }
+
+    let mut treasury_fee = creation_fee;
+    if let Some(referrer) = &ctx.accounts.referrer {
+        require_keys_neq!(
+            referrer.key(),
+            ctx.accounts.payer.key(),
+            FactoryError::SelfReferral
+        );
+
+        let referral_fee = (creation_fee as u128
+            * ctx.accounts.factory_config.referral_fee_bps as u128
+            / MAX_BPS as u128) as u64;
+
+        // A share that would leave an unfunded referrer below rent exemption would fail the
+        // whole creation, so it goes to the treasury instead.
+        let referrer_info = referrer.to_account_info();
+        let rent_exempt_minimum = Rent::get()?.minimum_balance(referrer_info.data_len());
+        let referrer_stays_rent_exempt =
+            referrer_info.lamports().saturating_add(referral_fee) >= rent_exempt_minimum;
+
+        if referral_fee > 0 && referrer_stays_rent_exempt {
+            treasury_fee -= referral_fee;
+            pay_lamports(&ctx, &referrer_info, referral_fee)?;
+
+            emit!(CreationFeeReferral {
+                mint: ctx.accounts.mint.key(),
+                creator: ctx.accounts.payer.key(),
+                referrer: referrer.key(),
+                amount: referral_fee,
+            });
+        }
+    }
+
+    pay_lamports(
+        &ctx,
+        &ctx.accounts.treasury_account.to_account_info(),
+        treasury_fee,
+    )
+}
+
+fn pay_lamports<'info>(
+    ctx: &Context<'_, '_, 'info, 'info, CreateToken<'info>>,
+    to: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
     system_program::transfer(
         CpiContext::new(
             ctx.accounts.system_program.to_account_info(),
             system_program::Transfer {
                 from: ctx.accounts.payer.to_account_info(),
-                to: ctx.accounts.treasury_account.to_account_info(),
+                to: to.clone(),
             },
         ),
-        ctx.accounts.factory_config.creation_fee_lamports,
+        amount,
+    )
+}
+
+/// SPL fee prices get the same discounts as the lamport fee; the Token-2022 surcharge
+/// and referral share only apply to lamport fees.
+fn pay_fee_in_tokens<'info>(
+    ctx: &Context<'_, '_, 'info, 'info, CreateToken<'info>>,
+    discount_bps: u16,
+    max_fee_amount: u64,
+) -> Result<()> {
+    let accounts = &ctx.accounts;
+    let (
+        Some(fee_mint_config),
+        Some(fee_mint),
+        Some(payer_fee_ata),
+        Some(treasury_fee_ata),
+        Some(fee_token_program),
+    ) = (
+        &accounts.fee_mint_config,
+        &accounts.fee_mint,
+        &accounts.payer_fee_ata,
+        &accounts.treasury_fee_ata,
+        &accounts.fee_token_program,
+    )
+    else {
+        return err!(FactoryError::InvalidFeeMintAccounts);
+    };
+
+    require!(
+        fee_mint.key() == fee_mint_config.mint
+            && treasury_fee_ata.key() == fee_mint_config.treasury_ata
+            && fee_mint.to_account_info().owner == &fee_token_program.key(),
+        FactoryError::InvalidFeeMintAccounts
+    );
+
+    // Send enough to cover any Token-2022 transfer fee so the treasury receives the full price.
+    let price = apply_discount(fee_mint_config.price, discount_bps);
+    require!(price <= max_fee_amount, FactoryError::FeeExceedsMax);
+    if price == 0 {
+        return Ok(());
+    }
+
+    let fee_mint_info = fee_mint.to_account_info();
+    let gross_price = gross_transfer_amount(&fee_mint_info, price)?;
+    require!(
+        payer_fee_ata.amount >= gross_price,
+        FactoryError::InsufficientCreationFee
+    );
+
+    transfer_checked_with_hooks(
+        &fee_token_program.to_account_info(),
+        &payer_fee_ata.to_account_info(),
+        &fee_mint_info,
+        &treasury_fee_ata.to_account_info(),
+        &accounts.payer.to_account_info(),
+        ctx.remaining_accounts,
+        gross_price,
+        fee_mint.decimals,
+        &[],
     )?;
 
     Ok(())
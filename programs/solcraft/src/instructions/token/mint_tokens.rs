@@ -3,9 +3,20 @@ use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token_interface::{mint_to, Mint, MintTo, TokenAccount, TokenInterface};
 
+use crate::constants::{PAUSE_TOKEN, PROGRAM_STATE_SEEDS};
+use crate::errors::GuardianError;
+use crate::states::ProgramState;
+
 #[derive(Accounts)]
 #[instruction(amount:u64)]
 pub struct MintTokens<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PAUSE_TOKEN)? @ GuardianError::ModulePaused,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
     #[account(mut)]
     pub mint: InterfaceAccount<'info, Mint>,
 
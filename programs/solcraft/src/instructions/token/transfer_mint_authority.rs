@@ -3,9 +3,20 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_2022::spl_token_2022::instruction::AuthorityType;
 use anchor_spl::token_interface::{set_authority, Mint, SetAuthority, TokenInterface};
 
+use crate::constants::{PAUSE_TOKEN, PROGRAM_STATE_SEEDS};
+use crate::errors::GuardianError;
+use crate::states::ProgramState;
+
 #[derive(Accounts)]
 #[instruction(new_authority: Option<Pubkey>)]
 pub struct TransferMintAuthority<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEEDS.as_bytes()],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PAUSE_TOKEN)? @ GuardianError::ModulePaused,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
     #[account(mut)]
     pub mint: InterfaceAccount<'info, Mint>,
 
@@ -27,11 +38,7 @@ pub fn transfer_mint_authority(
         },
     );
 
-    set_authority(
-        set_authority_ctx,
-        AuthorityType::MintTokens,
-        new_authority.into(),
-    )?;
+    set_authority(set_authority_ctx, AuthorityType::MintTokens, new_authority)?;
 
     Ok(())
 }
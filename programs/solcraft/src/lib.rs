@@ -2,10 +2,13 @@ use anchor_lang::prelude::*;
 
 mod constants;
 mod errors;
+mod events;
 mod instructions;
 mod states;
+mod utils;
 
 use instructions::*;
+use states::{AdminAction, CooldownMode, FeeRecipient, ProposalAction, Role};
 
 declare_id!("CADbArgTHGSsSiMJfXdtGYjQeLRf55f6QoQW7bNphicC");
 
@@ -13,6 +16,30 @@ declare_id!("CADbArgTHGSsSiMJfXdtGYjQeLRf55f6QoQW7bNphicC");
 pub mod solcraft {
     use super::*;
 
+    pub fn initialize_program_state(
+        ctx: Context<InitializeProgramState>,
+        guardian: Pubkey,
+        max_pause_seconds: u64,
+    ) -> Result<()> {
+        instructions::guardian::initialize_program_state(ctx, guardian, max_pause_seconds)
+    }
+
+    pub fn set_guardian(
+        ctx: Context<SetGuardian>,
+        guardian: Pubkey,
+        max_pause_seconds: u64,
+    ) -> Result<()> {
+        instructions::guardian::set_guardian(ctx, guardian, max_pause_seconds)
+    }
+
+    pub fn guardian_pause(ctx: Context<GuardianPause>, modules: u8) -> Result<()> {
+        instructions::guardian::guardian_pause(ctx, modules)
+    }
+
+    pub fn guardian_unpause(ctx: Context<GuardianUnpause>, modules: u8) -> Result<()> {
+        instructions::guardian::guardian_unpause(ctx, modules)
+    }
+
     pub fn initialize_factory(
         ctx: Context<InitializeFactory>,
         creation_fee_lamports: u64,
@@ -27,6 +54,95 @@ pub mod solcraft {
         instructions::factory::update_creation_fee(ctx, creation_fee_lamports)
     }
 
+    pub fn set_fee_mint(ctx: Context<SetFeeMint>, price: u64, enabled: bool) -> Result<()> {
+        instructions::factory::set_fee_mint(ctx, price, enabled)
+    }
+
+    pub fn update_fee_schedule(
+        ctx: Context<UpdateFeeSchedule>,
+        token_2022_surcharge_lamports: u64,
+        volume_discount_threshold: u64,
+        volume_discount_bps: u16,
+    ) -> Result<()> {
+        instructions::factory::update_fee_schedule(
+            ctx,
+            token_2022_surcharge_lamports,
+            volume_discount_threshold,
+            volume_discount_bps,
+        )
+    }
+
+    pub fn update_referral_fee(
+        ctx: Context<UpdateReferralFee>,
+        referral_fee_bps: u16,
+    ) -> Result<()> {
+        instructions::factory::update_referral_fee(ctx, referral_fee_bps)
+    }
+
+    pub fn set_fee_discount(ctx: Context<SetFeeDiscount>, discount_bps: u16) -> Result<()> {
+        instructions::factory::set_fee_discount(ctx, discount_bps)
+    }
+
+    pub fn remove_fee_discount(ctx: Context<RemoveFeeDiscount>) -> Result<()> {
+        instructions::factory::remove_fee_discount(ctx)
+    }
+
+    pub fn configure_timelock(
+        ctx: Context<ConfigureTimelock>,
+        delay_seconds: u64,
+        withdrawal_threshold: u64,
+    ) -> Result<()> {
+        instructions::factory::configure_timelock(ctx, delay_seconds, withdrawal_threshold)
+    }
+
+    pub fn queue_admin_action(ctx: Context<QueueAdminAction>, action: AdminAction) -> Result<()> {
+        instructions::factory::queue_admin_action(ctx, action)
+    }
+
+    pub fn execute_admin_action<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteAdminAction<'info>>,
+    ) -> Result<()> {
+        instructions::factory::execute_admin_action(ctx)
+    }
+
+    pub fn cancel_admin_action(ctx: Context<CancelAdminAction>) -> Result<()> {
+        instructions::factory::cancel_admin_action(ctx)
+    }
+
+    pub fn set_admin_signers(
+        ctx: Context<SetAdminSigners>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::factory::set_admin_signers(ctx, signers, threshold)
+    }
+
+    pub fn create_proposal(ctx: Context<CreateProposal>, action: ProposalAction) -> Result<()> {
+        instructions::factory::create_proposal(ctx, action)
+    }
+
+    pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+        instructions::factory::approve_proposal(ctx)
+    }
+
+    pub fn execute_proposal<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteProposal<'info>>,
+    ) -> Result<()> {
+        instructions::factory::execute_proposal(ctx)
+    }
+
+    pub fn grant_role(ctx: Context<GrantRole>, role: Role) -> Result<()> {
+        instructions::factory::grant_role(ctx, role)
+    }
+
+    pub fn revoke_role(ctx: Context<RevokeRole>) -> Result<()> {
+        instructions::factory::revoke_role(ctx)
+    }
+
+    pub fn migrate_factory_config(ctx: Context<MigrateFactoryConfig>) -> Result<()> {
+        instructions::factory::migrate_factory_config(ctx)
+    }
+
     pub fn pause_factory(ctx: Context<PauseFactory>) -> Result<()> {
         instructions::factory::pause_factory(ctx)
     }
@@ -35,19 +151,47 @@ pub mod solcraft {
         instructions::factory::unpause_factory(ctx)
     }
 
-    pub fn withdraw_fees(ctx: Context<WithdrawFees>) -> Result<()> {
-        instructions::factory::withdraw_fees(ctx)
+    pub fn withdraw_fees<'info>(
+        ctx: Context<'_, '_, 'info, 'info, WithdrawFees<'info>>,
+        amount: Option<u64>,
+    ) -> Result<()> {
+        instructions::factory::withdraw_fees(ctx, amount)
+    }
+
+    pub fn set_fee_recipients(
+        ctx: Context<SetFeeRecipients>,
+        fee_recipients: Vec<FeeRecipient>,
+    ) -> Result<()> {
+        instructions::factory::set_fee_recipients(ctx, fee_recipients)
+    }
+
+    pub fn distribute_fees<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DistributeFees<'info>>,
+    ) -> Result<()> {
+        instructions::factory::distribute_fees(ctx)
     }
 
-    pub fn create_token(
-        ctx: Context<CreateToken>,
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_token<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateToken<'info>>,
         name: String,
         symbol: String,
         uri: String,
         decimals: u8,
         supply: u64,
+        max_fee_lamports: u64,
+        max_fee_amount: u64,
     ) -> Result<()> {
-        instructions::token::create_token(ctx, name, symbol, uri, decimals, supply)
+        instructions::token::create_token(
+            ctx,
+            name,
+            symbol,
+            uri,
+            decimals,
+            supply,
+            max_fee_lamports,
+            max_fee_amount,
+        )
     }
 
     pub fn mint_tokens(ctx: Context<MintTokens>, amount: u64) -> Result<()> {
@@ -72,15 +216,168 @@ pub mod solcraft {
         instructions::faucet::initialize(ctx)
     }
 
-    pub fn deposit_to_faucet(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+    pub fn deposit_to_faucet<'info>(
+        ctx: Context<'_, '_, 'info, 'info, Deposit<'info>>,
+        amount: u64,
+    ) -> Result<()> {
         instructions::faucet::deposit(ctx, amount)
     }
 
-    pub fn withdraw_from_faucet(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
+    pub fn withdraw_from_faucet<'info>(
+        ctx: Context<'_, '_, 'info, 'info, Withdraw<'info>>,
+        amount: u64,
+    ) -> Result<()> {
         instructions::faucet::withdraw(ctx, amount)
     }
 
-    pub fn claim_from_faucet(ctx: Context<Claim>) -> Result<()> {
+    pub fn withdraw_all_from_faucet<'info>(
+        ctx: Context<'_, '_, 'info, 'info, Withdraw<'info>>,
+    ) -> Result<()> {
+        instructions::faucet::withdraw_all(ctx)
+    }
+
+    pub fn claim_from_faucet<'info>(
+        ctx: Context<'_, '_, 'info, 'info, Claim<'info>>,
+    ) -> Result<()> {
         instructions::faucet::claim(ctx)
     }
+
+    pub fn claim_sponsored_from_faucet<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimSponsored<'info>>,
+    ) -> Result<()> {
+        instructions::faucet::claim_sponsored(ctx)
+    }
+
+    pub fn set_rent_sponsorship(ctx: Context<SetRentSponsorship>, enabled: bool) -> Result<()> {
+        instructions::faucet::set_rent_sponsorship(ctx, enabled)
+    }
+
+    pub fn withdraw_rent_vault(ctx: Context<WithdrawRentVault>, amount: u64) -> Result<()> {
+        instructions::faucet::withdraw_rent_vault(ctx, amount)
+    }
+
+    pub fn set_sponsored_pool(
+        ctx: Context<SetSponsoredPool>,
+        enabled: bool,
+        lock_seconds: u64,
+    ) -> Result<()> {
+        instructions::faucet::set_sponsored_pool(ctx, enabled, lock_seconds)
+    }
+
+    pub fn withdraw_sponsor_share<'info>(
+        ctx: Context<'_, '_, 'info, 'info, WithdrawSponsorShare<'info>>,
+    ) -> Result<()> {
+        instructions::faucet::withdraw_sponsor_share(ctx)
+    }
+
+    pub fn configure_drip(
+        ctx: Context<ConfigureDrip>,
+        rate_per_second: u64,
+        cap: u64,
+    ) -> Result<()> {
+        instructions::faucet::configure_drip(ctx, rate_per_second, cap)
+    }
+
+    pub fn set_cooldown_mode(
+        ctx: Context<SetCooldownMode>,
+        mode: CooldownMode,
+        cooldown: u64,
+    ) -> Result<()> {
+        instructions::faucet::set_cooldown_mode(ctx, mode, cooldown)
+    }
+
+    pub fn migrate_faucet_config(ctx: Context<MigrateFaucetConfig>) -> Result<()> {
+        instructions::faucet::migrate_config(ctx)
+    }
+
+    pub fn migrate_recipient_data(ctx: Context<MigrateRecipientData>) -> Result<()> {
+        instructions::faucet::migrate_recipient_data(ctx)
+    }
+
+    pub fn configure_referrals(
+        ctx: Context<ConfigureReferrals>,
+        bonus_amount: u64,
+        cooldown_seconds: u64,
+    ) -> Result<()> {
+        instructions::faucet::configure_referrals(ctx, bonus_amount, cooldown_seconds)
+    }
+
+    pub fn set_epoch_claim_limit(
+        ctx: Context<SetEpochClaimLimit>,
+        epoch_claim_limit: u64,
+    ) -> Result<()> {
+        instructions::faucet::set_epoch_claim_limit(ctx, epoch_claim_limit)
+    }
+
+    pub fn set_faucet_operator(
+        ctx: Context<SetFaucetOperator>,
+        operator: Option<Pubkey>,
+        max_claim_amount: u64,
+        min_cooldown_seconds: u64,
+    ) -> Result<()> {
+        instructions::faucet::set_operator(ctx, operator, max_claim_amount, min_cooldown_seconds)
+    }
+
+    pub fn pause_faucet(ctx: Context<PauseFaucet>) -> Result<()> {
+        instructions::faucet::pause(ctx)
+    }
+
+    pub fn unpause_faucet(ctx: Context<UnpauseFaucet>) -> Result<()> {
+        instructions::faucet::unpause(ctx)
+    }
+
+    pub fn update_faucet_settings(
+        ctx: Context<UpdateFaucetSettings>,
+        allowed_claim_amount: u64,
+        cooldown_seconds: u64,
+    ) -> Result<()> {
+        instructions::faucet::update_settings(ctx, allowed_claim_amount, cooldown_seconds)
+    }
+
+    pub fn initialize_sol_faucet(ctx: Context<InitializeSolFaucet>) -> Result<()> {
+        instructions::sol_faucet::initialize_sol_faucet(ctx)
+    }
+
+    pub fn deposit_to_sol_faucet(ctx: Context<DepositSol>, amount: u64) -> Result<()> {
+        instructions::sol_faucet::deposit_sol(ctx, amount)
+    }
+
+    pub fn withdraw_from_sol_faucet(ctx: Context<WithdrawSol>, amount: u64) -> Result<()> {
+        instructions::sol_faucet::withdraw_sol(ctx, amount)
+    }
+
+    pub fn claim_from_sol_faucet(ctx: Context<ClaimSol>) -> Result<()> {
+        instructions::sol_faucet::claim_sol(ctx)
+    }
+
+    pub fn initialize_bundle_faucet(ctx: Context<InitializeBundleFaucet>) -> Result<()> {
+        instructions::bundle_faucet::initialize_bundle_faucet(ctx)
+    }
+
+    pub fn add_bundle_entry(ctx: Context<AddBundleEntry>, amount: u64) -> Result<()> {
+        instructions::bundle_faucet::add_bundle_entry(ctx, amount)
+    }
+
+    pub fn withdraw_from_bundle<'info>(
+        ctx: Context<'_, '_, 'info, 'info, WithdrawFromBundle<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::bundle_faucet::withdraw_from_bundle(ctx, amount)
+    }
+
+    pub fn claim_bundle<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimBundle<'info>>,
+    ) -> Result<()> {
+        instructions::bundle_faucet::claim_bundle(ctx)
+    }
+
+    pub fn close_faucet<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseFaucet<'info>>,
+    ) -> Result<()> {
+        instructions::faucet::close(ctx)
+    }
+
+    pub fn close_recipient_data(ctx: Context<CloseRecipientData>) -> Result<()> {
+        instructions::faucet::close_recipient_data(ctx)
+    }
 }
@@ -1,5 +1,11 @@
+pub mod bundle_faucet;
 pub mod factory;
 pub mod faucet;
+pub mod program_state;
+pub mod sol_faucet;
 
+pub use bundle_faucet::*;
 pub use factory::*;
 pub use faucet::*;
+pub use program_state::*;
+pub use sol_faucet::*;
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_BUNDLE_ENTRIES;
+
+#[account]
+#[derive(InitSpace)]
+pub struct BundleFaucetConfig {
+    pub owner: Pubkey,
+    pub cooldown_seconds: u64,
+    pub bump: u8,
+    #[max_len(MAX_BUNDLE_ENTRIES)]
+    pub entries: Vec<BundleEntry>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct BundleEntry {
+    pub mint: Pubkey,
+    pub treasury_ata: Pubkey,
+    pub amount: u64,
+    // Owner of the mint, so bundles can mix SPL Token and Token-2022 mints.
+    pub token_program: Pubkey,
+}
@@ -1,8 +1,52 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::{
+    CONFIG_RESERVED_BYTES, DISCRIMINATOR, MAX_ADMIN_SIGNERS, MAX_BPS, MAX_FEE_RECIPIENTS,
+};
+use crate::errors::FactoryError;
+
 #[account]
 #[derive(InitSpace)]
 pub struct FactoryConfig {
+    // First, so a future migration can read it without knowing the rest of the layout.
+    pub version: u8,
+    pub admin: Pubkey,
+    pub paused: bool,
+    pub treasury_account: Pubkey,
+    pub bump: u8,
+    pub treasury_bump: u8,
+    pub creation_fee_lamports: u64,
+    // Fee schedule: extra lamports charged for Token-2022 mints, and a discount for creators
+    // who have already created at least `volume_discount_threshold` tokens.
+    pub token_2022_surcharge_lamports: u64,
+    pub volume_discount_threshold: u64,
+    pub volume_discount_bps: u16,
+    // Share of the lamport creation fee paid directly to the referrer passed to `create_token`.
+    pub referral_fee_bps: u16,
+    // Timelock: while `timelock_delay_seconds` is non-zero, fee changes, admin changes and
+    // withdrawals above `timelock_withdrawal_threshold` must be queued as a `PendingAction`.
+    // The threshold caps the lamports withdrawn directly per `timelock_delay_seconds` window
+    // starting at `withdrawal_window_start`, so it cannot be bypassed by splitting withdrawals.
+    pub timelock_delay_seconds: u64,
+    pub timelock_withdrawal_threshold: u64,
+    pub withdrawal_window_start: i64,
+    pub withdrawn_in_window: u64,
+    pub next_action_id: u64,
+    // Built-in M-of-N admin set: when `admin_signers` is non-empty, pausing, fee updates,
+    // withdrawals and admin-set changes require an approved `Proposal` instead of `admin`.
+    pub admin_threshold: u8,
+    pub next_proposal_id: u64,
+    #[max_len(MAX_ADMIN_SIGNERS)]
+    pub admin_signers: Vec<Pubkey>,
+    // Revenue split used by `distribute_fees`; shares sum to `MAX_BPS` when non-empty.
+    #[max_len(MAX_FEE_RECIPIENTS)]
+    pub fee_recipients: Vec<FeeRecipient>,
+    pub reserved: [u8; CONFIG_RESERVED_BYTES],
+}
+
+/// The original, unversioned `FactoryConfig` layout upgraded by `migrate_factory_config`.
+#[derive(AnchorDeserialize)]
+pub struct LegacyFactoryConfig {
     pub admin: Pubkey,
     pub paused: bool,
     pub treasury_account: Pubkey,
@@ -10,3 +54,282 @@ pub struct FactoryConfig {
     pub treasury_bump: u8,
     pub creation_fee_lamports: u64,
 }
+
+impl LegacyFactoryConfig {
+    pub const LEN: usize = DISCRIMINATOR + 32 + 1 + 32 + 1 + 1 + 8;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct FeeRecipient {
+    pub recipient: Pubkey,
+    pub bps: u16,
+}
+
+impl FactoryConfig {
+    pub fn is_timelocked(&self) -> bool {
+        self.timelock_delay_seconds > 0
+    }
+
+    /// Counts a direct withdrawal against the current timelock window, starting a new window
+    /// once the previous one has lasted `timelock_delay_seconds`.
+    pub fn record_direct_withdrawal(&mut self, amount: u64, now: i64) -> Result<()> {
+        if !self.is_timelocked() {
+            return Ok(());
+        }
+
+        if now
+            >= self
+                .withdrawal_window_start
+                .saturating_add(self.timelock_delay_seconds as i64)
+        {
+            self.withdrawal_window_start = now;
+            self.withdrawn_in_window = 0;
+        }
+
+        let withdrawn_in_window = self.withdrawn_in_window.saturating_add(amount);
+        require!(
+            withdrawn_in_window <= self.timelock_withdrawal_threshold,
+            FactoryError::TimelockRequired
+        );
+        self.withdrawn_in_window = withdrawn_in_window;
+
+        Ok(())
+    }
+
+    /// `admin` implicitly holds every role, and a `SuperAdmin` role grant covers every other role.
+    /// Under multisig only an explicit grant of `role` counts, so neither can bypass the admin set.
+    pub fn has_role(
+        &self,
+        authority: &Pubkey,
+        factory_role: Option<&FactoryRole>,
+        role: Role,
+    ) -> bool {
+        let is_multisig = self.is_multisig();
+        if *authority == self.admin && !is_multisig {
+            return true;
+        }
+
+        factory_role.is_some_and(|factory_role| {
+            factory_role.holder == *authority
+                && (factory_role.role == role
+                    || (factory_role.role == Role::SuperAdmin && !is_multisig))
+        })
+    }
+
+    pub fn is_multisig(&self) -> bool {
+        !self.admin_signers.is_empty()
+    }
+
+    pub fn is_admin_signer(&self, key: &Pubkey) -> bool {
+        self.admin_signers.contains(key)
+    }
+
+    /// Discounts do not stack; the larger of the volume and partner discounts applies.
+    pub fn discount_bps(&self, creator_stats: &CreatorStats, partner_discount_bps: u16) -> u16 {
+        let volume_discount_bps = if self.volume_discount_threshold > 0
+            && creator_stats.tokens_created >= self.volume_discount_threshold
+        {
+            self.volume_discount_bps
+        } else {
+            0
+        };
+
+        volume_discount_bps.max(partner_discount_bps).min(MAX_BPS)
+    }
+
+    pub fn creation_fee(&self, is_token_2022: bool, discount_bps: u16) -> u64 {
+        let mut fee = self.creation_fee_lamports;
+        if is_token_2022 {
+            fee = fee.saturating_add(self.token_2022_surcharge_lamports);
+        }
+
+        apply_discount(fee, discount_bps)
+    }
+}
+
+/// Returns `amount` reduced by `discount_bps` basis points, rounding the discount down.
+pub fn apply_discount(amount: u64, discount_bps: u16) -> u64 {
+    let discount = amount as u128 * discount_bps.min(MAX_BPS) as u128 / MAX_BPS as u128;
+    amount - discount as u64
+}
+
+/// Per-creator history used for volume discounts.
+#[account]
+#[derive(InitSpace)]
+pub struct CreatorStats {
+    pub creator: Pubkey,
+    pub tokens_created: u64,
+    pub total_fee_lamports_paid: u64,
+    pub bump: u8,
+}
+
+/// Admin-granted creation fee discount for a partner wallet; 10_000 bps waives the fee.
+#[account]
+#[derive(InitSpace)]
+pub struct FeeDiscount {
+    pub wallet: Pubkey,
+    pub discount_bps: u16,
+    pub bump: u8,
+}
+
+/// An SPL mint accepted as payment for the token creation fee.
+#[account]
+#[derive(InitSpace)]
+pub struct FeeMintConfig {
+    pub mint: Pubkey,
+    // Treasury ATA for `mint`, owned by the `FACTORY_TREASURY` PDA.
+    pub treasury_ata: Pubkey,
+    // Creation fee in base units of `mint`, as received by the treasury.
+    pub price: u64,
+    pub enabled: bool,
+    pub bump: u8,
+}
+
+/// A queued admin action that can be executed once `eta` has passed.
+#[account]
+#[derive(InitSpace)]
+pub struct PendingAction {
+    pub id: u64,
+    pub action: AdminAction,
+    pub queued_at: i64,
+    pub eta: i64,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub enum AdminAction {
+    UpdateCreationFee {
+        creation_fee_lamports: u64,
+    },
+    UpdateFeeSchedule {
+        token_2022_surcharge_lamports: u64,
+        volume_discount_threshold: u64,
+        volume_discount_bps: u16,
+    },
+    SetAdmin {
+        new_admin: Pubkey,
+    },
+    WithdrawFees {
+        amount: u64,
+        destination: Pubkey,
+    },
+    ConfigureTimelock {
+        delay_seconds: u64,
+        withdrawal_threshold: u64,
+    },
+    SweepTokenFees {
+        destination: Pubkey,
+    },
+    SetFeeMint {
+        mint: Pubkey,
+        price: u64,
+        enabled: bool,
+    },
+    SetFeeRecipients {
+        #[max_len(MAX_FEE_RECIPIENTS)]
+        recipients: Vec<FeeRecipient>,
+    },
+}
+
+/// A multisig admin proposal that executes once `admin_threshold` current signers approve it.
+/// Apart from pausing and unpausing, it also waits out `timelock_delay_seconds` from `created_at`.
+#[account]
+#[derive(InitSpace)]
+pub struct Proposal {
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub action: ProposalAction,
+    pub created_at: i64,
+    #[max_len(MAX_ADMIN_SIGNERS)]
+    pub approvals: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+impl Proposal {
+    /// Approvals from keys that have since been removed from the admin set do not count.
+    pub fn approval_count(&self, factory_config: &FactoryConfig) -> usize {
+        self.approvals
+            .iter()
+            .filter(|approver| factory_config.is_admin_signer(approver))
+            .count()
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub enum ProposalAction {
+    Pause,
+    Unpause,
+    UpdateCreationFee {
+        creation_fee_lamports: u64,
+    },
+    UpdateFeeSchedule {
+        token_2022_surcharge_lamports: u64,
+        volume_discount_threshold: u64,
+        volume_discount_bps: u16,
+    },
+    WithdrawFees {
+        amount: u64,
+        destination: Pubkey,
+    },
+    SetAdminSigners {
+        #[max_len(MAX_ADMIN_SIGNERS)]
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    },
+    SetFeeRecipients {
+        #[max_len(MAX_FEE_RECIPIENTS)]
+        recipients: Vec<FeeRecipient>,
+    },
+    UpdateReferralFee {
+        referral_fee_bps: u16,
+    },
+    ConfigureTimelock {
+        delay_seconds: u64,
+        withdrawal_threshold: u64,
+    },
+    SetFeeMint {
+        mint: Pubkey,
+        price: u64,
+        enabled: bool,
+    },
+    SetFeeDiscount {
+        wallet: Pubkey,
+        discount_bps: u16,
+    },
+    RemoveFeeDiscount {
+        wallet: Pubkey,
+    },
+    GrantRole {
+        holder: Pubkey,
+        role: Role,
+    },
+    RevokeRole {
+        holder: Pubkey,
+        role: Role,
+    },
+    CancelAdminAction {
+        action_id: u64,
+    },
+    SweepTokenFees {
+        destination: Pubkey,
+    },
+}
+
+/// A role granted to `holder` by the super admin. Role-gated instructions take the grant as an
+/// optional account, which the factory admin can omit unless the factory is under multisig.
+#[account]
+#[derive(InitSpace)]
+pub struct FactoryRole {
+    pub holder: Pubkey,
+    pub role: Role,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum Role {
+    // Can grant and revoke roles, and acts as every other role.
+    SuperAdmin,
+    Pauser,
+    FeeManager,
+    Treasurer,
+}
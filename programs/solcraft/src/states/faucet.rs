@@ -1,18 +1,310 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::{
+    CONFIG_RESERVED_BYTES, DISCRIMINATOR, FAUCET_RECIPIENT_VERSION, RECIPIENT_RESERVED_BYTES,
+};
+use crate::errors::FaucetError;
+
 #[account]
 #[derive(InitSpace)]
 pub struct FaucetConfig {
+    // Leads the layout so migrations can tell versions apart before parsing the rest.
+    pub version: u8,
     pub owner: Pubkey,
     pub mint: Pubkey,
     pub allowed_claim_amount: u64,
     pub treasury_ata: Pubkey,
     pub cooldown_seconds: u64,
     pub bump: u8,
+    pub sponsor_rent: bool,
+    // Treasury-side totals: net tokens credited by deposits and gross tokens sent out by claims.
+    pub total_deposited: u64,
+    pub total_claimed: u64,
+    // Sponsored pool mode: deposits mint pro-rata shares of the treasury that sponsors can redeem.
+    pub sponsored_pool: bool,
+    pub sponsor_lock_seconds: u64,
+    pub total_shares: u64,
+    // Bumped whenever claims drain the pool to zero, invalidating all outstanding shares.
+    pub pool_generation: u32,
+    // Drip mode: when `drip_rate_per_second` is non-zero, recipients accrue tokens over time
+    // up to `drip_cap` instead of claiming `allowed_claim_amount` once per cooldown.
+    pub drip_rate_per_second: u64,
+    pub drip_cap: u64,
+    // Cooldown mode: fixed-amount claims can be spaced by slots instead of unix timestamps,
+    // which is deterministic on local validators and test harnesses. Drip accrual is always
+    // measured in seconds.
+    pub cooldown_mode: CooldownMode,
+    pub cooldown_slots: u64,
+    // Hard cap on claims per Solana epoch across all recipients; zero means unlimited.
+    pub epoch_claim_limit: u64,
+    pub current_epoch: u64,
+    pub claims_this_epoch: u64,
+    // Optional key that can pause and tune the faucet but never withdraw from it. The owner
+    // bounds how far the operator may loosen claims: per-claim amounts (fixed, drip cap and
+    // referral bonus) and the minimum wait between them.
+    pub operator: Option<Pubkey>,
+    pub operator_max_claim_amount: u64,
+    pub operator_min_cooldown_seconds: u64,
+    pub paused: bool,
+    // Referral rewards: bonus paid to a referrer when a new recipient names them on first claim,
+    // at most once per `referral_cooldown_seconds` per referrer. A zero bonus disables referrals.
+    pub referral_bonus_amount: u64,
+    pub referral_cooldown_seconds: u64,
+    pub reserved: [u8; CONFIG_RESERVED_BYTES],
+}
+
+/// The original, unversioned `FaucetConfig` layout upgraded by `migrate_faucet_config`.
+#[derive(AnchorDeserialize)]
+pub struct LegacyFaucetConfig {
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+    pub allowed_claim_amount: u64,
+    pub treasury_ata: Pubkey,
+    pub cooldown_seconds: u64,
+    pub bump: u8,
+}
+
+impl LegacyFaucetConfig {
+    pub const LEN: usize = DISCRIMINATOR + 32 + 32 + 8 + 32 + 8 + 1;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum CooldownMode {
+    UnixTimestamp,
+    Slot,
+}
+
+impl FaucetConfig {
+    pub fn is_owner_or_operator(&self, key: &Pubkey) -> bool {
+        *key == self.owner || self.operator == Some(*key)
+    }
+
+    /// Rejects operator changes that pay more than `operator_max_claim_amount` per claim or
+    /// allow claims more often than `operator_min_cooldown_seconds`. The owner is unbounded.
+    pub fn check_operator_bounds(
+        &self,
+        authority: &Pubkey,
+        claim_amount: u64,
+        cooldown_seconds: u64,
+    ) -> Result<()> {
+        if *authority == self.owner {
+            return Ok(());
+        }
+
+        require!(
+            claim_amount <= self.operator_max_claim_amount
+                && cooldown_seconds >= self.operator_min_cooldown_seconds,
+            FaucetError::OperatorLimitExceeded
+        );
+
+        Ok(())
+    }
+
+    /// Lets the operator set or tighten the per-epoch cap, but never loosen or remove it.
+    pub fn check_operator_epoch_claim_limit(
+        &self,
+        authority: &Pubkey,
+        epoch_claim_limit: u64,
+    ) -> Result<()> {
+        if *authority == self.owner {
+            return Ok(());
+        }
+
+        require!(
+            epoch_claim_limit > 0
+                && (self.epoch_claim_limit == 0 || epoch_claim_limit <= self.epoch_claim_limit),
+            FaucetError::OperatorLimitExceeded
+        );
+
+        Ok(())
+    }
+
+    /// Counts a claim against the epoch quota, resetting the counter when the epoch advances.
+    pub fn record_epoch_claim(&mut self, clock: &Clock) -> Result<()> {
+        if clock.epoch != self.current_epoch {
+            self.current_epoch = clock.epoch;
+            self.claims_this_epoch = 0;
+        }
+
+        require!(
+            self.epoch_claim_limit == 0 || self.claims_this_epoch < self.epoch_claim_limit,
+            FaucetError::EpochClaimLimitReached
+        );
+        self.claims_this_epoch += 1;
+
+        Ok(())
+    }
+
+    pub fn is_drip_mode(&self) -> bool {
+        self.drip_rate_per_second > 0
+    }
+
+    /// Amount the recipient may claim at `clock`.
+    /// Fails with `CooldownNotElapsed` in cooldown mode and `NothingToClaim` in drip mode.
+    pub fn claimable_amount(
+        &self,
+        recipient_data: &FaucetRecipientData,
+        clock: &Clock,
+    ) -> Result<u64> {
+        if !self.is_drip_mode() {
+            require!(
+                self.cooldown_elapsed(recipient_data, clock),
+                FaucetError::CooldownNotElapsed
+            );
+            return Ok(self.allowed_claim_amount);
+        }
+
+        let accrued = self.accrued_drip(recipient_data, clock.unix_timestamp);
+        require!(accrued > 0, FaucetError::NothingToClaim);
+
+        Ok(accrued)
+    }
+
+    /// Returns true once the recipient is entitled to a full claim again, so dropping their
+    /// record cannot be used to claim early.
+    pub fn is_fully_recharged(&self, recipient_data: &FaucetRecipientData, clock: &Clock) -> bool {
+        if self.is_drip_mode() {
+            self.accrued_drip(recipient_data, clock.unix_timestamp) >= self.drip_cap
+        } else {
+            self.cooldown_elapsed(recipient_data, clock)
+        }
+    }
+
+    fn cooldown_elapsed(&self, recipient_data: &FaucetRecipientData, clock: &Clock) -> bool {
+        match self.cooldown_mode {
+            CooldownMode::UnixTimestamp => {
+                recipient_data.cooldown_elapsed(self.cooldown_seconds, clock.unix_timestamp)
+            }
+            CooldownMode::Slot => {
+                recipient_data.slot_cooldown_elapsed(self.cooldown_slots, clock.slot)
+            }
+        }
+    }
+
+    // A recipient that has never claimed starts with a full bucket.
+    fn accrued_drip(&self, recipient_data: &FaucetRecipientData, current_timestamp: i64) -> u64 {
+        if recipient_data.last_claimed_at == 0 {
+            return self.drip_cap;
+        }
+
+        let elapsed = current_timestamp
+            .saturating_sub(recipient_data.last_claimed_at)
+            .max(0) as u64;
+        elapsed
+            .saturating_mul(self.drip_rate_per_second)
+            .min(self.drip_cap)
+    }
+
+    /// Shares minted for a deposit of `amount` into a pool currently holding `pool_balance`.
+    pub fn shares_for_deposit(&self, amount: u64, pool_balance: u64) -> Result<u64> {
+        if self.total_shares == 0 || pool_balance == 0 {
+            return Ok(amount);
+        }
+
+        let shares = (amount as u128) * (self.total_shares as u128) / (pool_balance as u128);
+        u64::try_from(shares).map_err(|_| FaucetError::MathOverflow.into())
+    }
+
+    /// Tokens redeemable for `shares` out of a pool currently holding `pool_balance`.
+    pub fn amount_for_shares(&self, shares: u64, pool_balance: u64) -> Result<u64> {
+        if self.total_shares == 0 {
+            return Ok(0);
+        }
+
+        let amount = (shares as u128) * (pool_balance as u128) / (self.total_shares as u128);
+        u64::try_from(amount).map_err(|_| FaucetError::MathOverflow.into())
+    }
+}
+
+/// Outlives the faucet config so a re-initialized faucet continues the pool generation count,
+/// keeping depositor records from a closed faucet from counting as shares in the new one.
+#[account]
+#[derive(InitSpace)]
+pub struct FaucetPoolCounter {
+    pub next_pool_generation: u32,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct FaucetDepositorRecord {
+    pub faucet: Pubkey,
+    pub depositor: Pubkey,
+    pub total_deposited: u64,
+    pub last_deposited_at: i64,
+    pub bump: u8,
+    pub shares: u64,
+    pub pool_generation: u32,
+}
+
+impl FaucetDepositorRecord {
+    /// Shares still backed by the pool; shares from a drained pool generation are worthless.
+    pub fn active_shares(&self, faucet_config: &FaucetConfig) -> u64 {
+        if self.pool_generation == faucet_config.pool_generation {
+            self.shares
+        } else {
+            0
+        }
+    }
 }
 
 #[account]
 #[derive(InitSpace)]
 pub struct FaucetRecipientData {
+    // Kept first so any future layout can be recognised before the rest is parsed.
+    pub version: u8,
+    pub last_claimed_at: i64,
+    pub last_claimed_slot: u64,
+    pub referrer: Option<Pubkey>,
+    pub referral_count: u64,
+    pub last_referral_rewarded_at: i64,
+    // Whoever funded this record's rent: the recipient, a relayer, or the faucet rent vault
+    // when it reimbursed a relayer. Closing the record refunds them, not the recipient.
+    pub rent_payer: Pubkey,
+    pub reserved: [u8; RECIPIENT_RESERVED_BYTES],
+}
+
+/// The original recipient record layout upgraded by `migrate_recipient_data`.
+#[derive(AnchorDeserialize)]
+pub struct LegacyFaucetRecipientData {
     pub last_claimed_at: i64,
 }
+
+impl LegacyFaucetRecipientData {
+    pub const LEN: usize = DISCRIMINATOR + 8;
+}
+
+impl FaucetRecipientData {
+    /// Stamps a record created by the current claim with its layout version and rent payer.
+    pub fn initialize(&mut self, rent_payer: Pubkey) {
+        self.version = FAUCET_RECIPIENT_VERSION;
+        self.rent_payer = rent_payer;
+    }
+
+    /// Returns true once `cooldown_seconds` have passed since the last claim.
+    /// A recipient that has never claimed (`last_claimed_at == 0`) is never on cooldown.
+    pub fn cooldown_elapsed(&self, cooldown_seconds: u64, current_timestamp: i64) -> bool {
+        self.last_claimed_at == 0
+            || current_timestamp - self.last_claimed_at >= cooldown_seconds as i64
+    }
+
+    /// Slot-based counterpart of `cooldown_elapsed`.
+    pub fn slot_cooldown_elapsed(&self, cooldown_slots: u64, current_slot: u64) -> bool {
+        !self.has_claimed() || current_slot.saturating_sub(self.last_claimed_slot) >= cooldown_slots
+    }
+
+    /// True once the record names a referrer or has referred anyone; closing it would let
+    /// the wallet start over as a new recipient and refer or be referred again.
+    pub fn has_referral_state(&self) -> bool {
+        self.referrer.is_some() || self.referral_count > 0 || self.last_referral_rewarded_at != 0
+    }
+
+    pub fn has_claimed(&self) -> bool {
+        self.last_claimed_at != 0 || self.last_claimed_slot != 0
+    }
+
+    pub fn record_claim(&mut self, clock: &Clock) {
+        self.last_claimed_at = clock.unix_timestamp;
+        self.last_claimed_slot = clock.slot;
+    }
+}
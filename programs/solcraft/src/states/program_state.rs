@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+/// Program-wide emergency switch. The guardian can pause individual modules, and every pause
+/// lapses automatically at `pause_expires_at` so a lost guardian key cannot freeze the program.
+#[account]
+#[derive(InitSpace)]
+pub struct ProgramState {
+    // Upgrade authority at initialization; manages the guardian.
+    pub authority: Pubkey,
+    pub guardian: Pubkey,
+    // Bitmask of `PAUSE_*` module flags.
+    pub paused_modules: u8,
+    pub pause_expires_at: i64,
+    pub max_pause_seconds: u64,
+    pub bump: u8,
+}
+
+impl ProgramState {
+    pub fn is_paused(&self, module: u8) -> Result<bool> {
+        if self.paused_modules & module == 0 {
+            return Ok(false);
+        }
+
+        Ok(Clock::get()?.unix_timestamp < self.pause_expires_at)
+    }
+}
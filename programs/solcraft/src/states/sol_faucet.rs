@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct SolFaucetConfig {
+    pub owner: Pubkey,
+    pub vault: Pubkey,
+    pub allowed_claim_lamports: u64,
+    pub cooldown_seconds: u64,
+    pub bump: u8,
+    pub vault_bump: u8,
+}
@@ -0,0 +1,215 @@
+use anchor_lang::{prelude::*, solana_program::program::invoke, system_program};
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{
+        transfer_fee::{self, TransferFeeConfig},
+        transfer_hook, BaseStateWithExtensions, StateWithExtensions,
+    },
+    onchain::invoke_transfer_checked,
+};
+
+use crate::errors::FaucetError;
+
+/// Performs a `transfer_checked` CPI that works for both SPL Token and Token-2022 mints.
+/// Any `additional_accounts` (e.g. transfer-hook extra accounts) are forwarded to the token program;
+/// instructions that move tokens pass their remaining accounts through here.
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_checked_with_hooks<'info>(
+    token_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    additional_accounts: &[AccountInfo<'info>],
+    amount: u64,
+    decimals: u8,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    invoke_transfer_checked(
+        token_program.key,
+        from.clone(),
+        mint.clone(),
+        to.clone(),
+        authority.clone(),
+        additional_accounts,
+        amount,
+        decimals,
+        signer_seeds,
+    )?;
+
+    Ok(())
+}
+
+/// Returns the transfer fee the mint withholds when `amount` is sent, or zero for
+/// mints without the Token-2022 transfer-fee extension.
+pub fn transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    let Some(fee_config) = transfer_fee_config(mint)? else {
+        return Ok(0);
+    };
+
+    let epoch = Clock::get()?.epoch;
+    let fee = fee_config
+        .calculate_epoch_fee(epoch, amount)
+        .ok_or(FaucetError::TransferFeeOverflow)?;
+
+    Ok(fee)
+}
+
+/// Returns the amount that must be sent so that the receiver ends up with exactly `net_amount`
+/// after the mint's transfer fee is withheld.
+pub fn gross_transfer_amount(mint: &AccountInfo, net_amount: u64) -> Result<u64> {
+    let Some(fee_config) = transfer_fee_config(mint)? else {
+        return Ok(net_amount);
+    };
+
+    let epoch = Clock::get()?.epoch;
+    let fee = fee_config
+        .calculate_inverse_epoch_fee(epoch, net_amount)
+        .ok_or(FaucetError::TransferFeeOverflow)?;
+
+    net_amount
+        .checked_add(fee)
+        .ok_or(FaucetError::TransferFeeOverflow.into())
+}
+
+/// Moves any transfer fees withheld in `token_account` to its mint, since Token-2022 refuses
+/// to close accounts that still hold withheld fees. A no-op for mints without transfer fees.
+pub fn harvest_withheld_fees<'info>(
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    token_account: &AccountInfo<'info>,
+) -> Result<()> {
+    if transfer_fee_config(mint)?.is_none() {
+        return Ok(());
+    }
+
+    let ix = transfer_fee::instruction::harvest_withheld_tokens_to_mint(
+        token_program.key,
+        mint.key,
+        &[token_account.key],
+    )?;
+    invoke(
+        &ix,
+        &[token_program.clone(), mint.clone(), token_account.clone()],
+    )?;
+
+    Ok(())
+}
+
+/// Returns true for Token-2022 mints with a transfer-hook program configured.
+pub fn has_transfer_hook(mint: &AccountInfo) -> Result<bool> {
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(false);
+    }
+
+    let mint_data = mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+
+    Ok(transfer_hook::get_program_id(&mint_state).is_some())
+}
+
+fn transfer_fee_config(mint: &AccountInfo) -> Result<Option<TransferFeeConfig>> {
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(None);
+    }
+
+    let mint_data = mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+
+    Ok(mint_state
+        .get_extension::<TransferFeeConfig>()
+        .ok()
+        .copied())
+}
+
+/// Grows `account` to `new_len` bytes, topping it up from `payer` so it stays rent-exempt.
+pub fn realloc_rent_exempt<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_len: usize,
+) -> Result<()> {
+    let required_lamports = Rent::get()?.minimum_balance(new_len);
+    let top_up = required_lamports.saturating_sub(account.lamports());
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+
+    account.resize(new_len)?;
+
+    Ok(())
+}
+
+/// Creates a program-owned account of `space` bytes at the PDA `account`, as Anchor's `init`
+/// does, for accounts whose address is only known once an instruction's data has been read.
+/// `signer_seeds` must include the bump.
+pub fn create_pda_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let required_lamports = Rent::get()?.minimum_balance(space);
+    let signer = &[signer_seeds];
+
+    if account.lamports() == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::CreateAccount {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+                signer,
+            ),
+            required_lamports,
+            space as u64,
+            &crate::ID,
+        );
+    }
+
+    // `create_account` rejects funded addresses, so a pre-funded PDA is topped up and claimed.
+    let top_up = required_lamports.saturating_sub(account.lamports());
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Allocate {
+                account_to_allocate: account.clone(),
+            },
+            signer,
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Assign {
+                account_to_assign: account.clone(),
+            },
+            signer,
+        ),
+        &crate::ID,
+    )
+}
@@ -0,0 +1,198 @@
+import { BN } from "bn.js";
+import { expect } from "chai";
+import * as anchor from "@coral-xyz/anchor";
+import {
+  airdropSol,
+  initializeProgramState,
+  user1,
+  user2,
+  program,
+} from "./setup";
+import {
+  createInitializeMintInstruction,
+  createInitializeTransferHookInstruction,
+  createMint,
+  ExtensionType,
+  getMintLen,
+  getAccount,
+  getAssociatedTokenAddress,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+  TOKEN_2022_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import { getBundleFaucetPDA } from "./pdas";
+
+describe("Bundle Faucet", () => {
+  const CLAIM_AMOUNTS = [new BN(1_000_000), new BN(5_000_000)];
+  // Bundles may mix SPL Token and Token-2022 mints.
+  const TOKEN_PROGRAMS = [TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID];
+  let mints: anchor.web3.PublicKey[] = [];
+  let treasuryAtas: anchor.web3.PublicKey[] = [];
+  let bundleFaucetPda: anchor.web3.PublicKey;
+
+  before(async () => {
+    // Airdrop some SOL to the users before tests
+    await airdropSol(user1.publicKey, 2);
+    await airdropSol(user2.publicKey, 2);
+    await initializeProgramState();
+
+    bundleFaucetPda = getBundleFaucetPDA(program);
+
+    for (let i = 0; i < CLAIM_AMOUNTS.length; i++) {
+      const mint = await createMint(
+        program.provider.connection,
+        user1.payer,
+        user1.publicKey,
+        null,
+        6,
+        undefined,
+        undefined,
+        TOKEN_PROGRAMS[i]
+      );
+      mints.push(mint);
+      treasuryAtas.push(
+        await getAssociatedTokenAddress(
+          mint,
+          bundleFaucetPda,
+          true,
+          TOKEN_PROGRAMS[i]
+        )
+      );
+    }
+  });
+
+  it("Initialized!", async () => {
+    await program.methods
+      .initializeBundleFaucet()
+      .accounts({
+        owner: user1.publicKey,
+      })
+      .rpc();
+
+    for (let i = 0; i < mints.length; i++) {
+      await program.methods
+        .addBundleEntry(CLAIM_AMOUNTS[i])
+        .accounts({
+          mint: mints[i],
+          owner: user1.publicKey,
+          tokenProgram: TOKEN_PROGRAMS[i],
+        })
+        .rpc();
+
+      await mintTo(
+        program.provider.connection,
+        user1.payer,
+        mints[i],
+        treasuryAtas[i],
+        user1.publicKey,
+        BigInt(CLAIM_AMOUNTS[i].muln(10).toString()),
+        [],
+        undefined,
+        TOKEN_PROGRAMS[i]
+      );
+    }
+
+    const bundleFaucetConfig = await program.account.bundleFaucetConfig.fetch(
+      bundleFaucetPda
+    );
+
+    expect(bundleFaucetConfig.entries.length).to.equal(mints.length);
+  });
+
+  it("Claims every token in the bundle at once!", async () => {
+    const remainingAccounts: anchor.web3.AccountMeta[] = [];
+    const recipientAtas: anchor.web3.PublicKey[] = [];
+
+    for (let i = 0; i < mints.length; i++) {
+      const recipientAta = await getOrCreateAssociatedTokenAccount(
+        program.provider.connection,
+        user2,
+        mints[i],
+        user2.publicKey,
+        false,
+        undefined,
+        undefined,
+        TOKEN_PROGRAMS[i]
+      );
+      recipientAtas.push(recipientAta.address);
+
+      remainingAccounts.push(
+        { pubkey: mints[i], isSigner: false, isWritable: false },
+        { pubkey: treasuryAtas[i], isSigner: false, isWritable: true },
+        { pubkey: recipientAta.address, isSigner: false, isWritable: true },
+        { pubkey: TOKEN_PROGRAMS[i], isSigner: false, isWritable: false }
+      );
+    }
+
+    await program.methods
+      .claimBundle()
+      .accounts({
+        recipient: user2.publicKey,
+      })
+      .remainingAccounts(remainingAccounts)
+      .signers([user2])
+      .rpc();
+
+    for (let i = 0; i < mints.length; i++) {
+      const recipientAtaInfo = await getAccount(
+        program.provider.connection,
+        recipientAtas[i],
+        undefined,
+        TOKEN_PROGRAMS[i]
+      );
+      expect(recipientAtaInfo.amount).to.eql(
+        BigInt(CLAIM_AMOUNTS[i].toString())
+      );
+    }
+  });
+
+  it("Rejects transfer-hook mints!", async () => {
+    const hookMint = anchor.web3.Keypair.generate();
+    const mintLen = getMintLen([ExtensionType.TransferHook]);
+    const lamports =
+      await program.provider.connection.getMinimumBalanceForRentExemption(
+        mintLen
+      );
+
+    // The hook program is never invoked, so any key will do.
+    const tx = new anchor.web3.Transaction().add(
+      anchor.web3.SystemProgram.createAccount({
+        fromPubkey: user1.publicKey,
+        newAccountPubkey: hookMint.publicKey,
+        space: mintLen,
+        lamports,
+        programId: TOKEN_2022_PROGRAM_ID,
+      }),
+      createInitializeTransferHookInstruction(
+        hookMint.publicKey,
+        user1.publicKey,
+        anchor.web3.Keypair.generate().publicKey,
+        TOKEN_2022_PROGRAM_ID
+      ),
+      createInitializeMintInstruction(
+        hookMint.publicKey,
+        6,
+        user1.publicKey,
+        null,
+        TOKEN_2022_PROGRAM_ID
+      )
+    );
+    await program.provider.sendAndConfirm(tx, [hookMint]);
+
+    try {
+      await program.methods
+        .addBundleEntry(new BN(1_000_000))
+        .accounts({
+          mint: hookMint.publicKey,
+          owner: user1.publicKey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .rpc({ commitment: "confirmed" });
+      expect.fail("The transaction should have failed");
+    } catch (err: any) {
+      const code = err?.error?.errorCode?.code;
+      expect(code).to.equal("TransferHookNotSupported");
+    }
+  });
+});
@@ -1,14 +1,33 @@
 import { BN } from "bn.js";
 import { expect } from "chai";
 import * as anchor from "@coral-xyz/anchor";
-import { getFactoryPDA, getTreasuryPDA } from "./pdas";
-import { airdropSol, LAMPORTS_FEE, user1, user2, program } from "./setup";
+import { createMint, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import {
+  getFactoryPDA,
+  getFactoryRolePDA,
+  getFeeDiscountPDA,
+  getFeeMintConfigPDA,
+  getPendingActionPDA,
+  getProposalPDA,
+  getTreasuryPDA,
+} from "./pdas";
+import {
+  airdropSol,
+  initializeProgramState,
+  LAMPORTS_FEE,
+  user1,
+  user2,
+  program,
+} from "./setup";
 
 describe("Factory", () => {
+  let feeMint: anchor.web3.PublicKey;
+
   before(async () => {
     // Airdrop some SOL to the user1 before tests
     await airdropSol(user1.publicKey, 2);
     await airdropSol(user2.publicKey, 2);
+    await initializeProgramState();
   });
 
   it("Initialized!", async () => {
@@ -33,7 +52,7 @@ describe("Factory", () => {
     await program.methods
       .updateCreationFee(new BN(NEW_FEE))
       .accounts({
-        admin: user1.publicKey,
+        authority: user1.publicKey,
       })
       .rpc();
 
@@ -50,7 +69,7 @@ describe("Factory", () => {
       await program.methods
         .updateCreationFee(new BN(NEW_FEE))
         .accounts({
-          admin: user2.publicKey,
+          authority: user2.publicKey,
         })
         .signers([user2])
         .rpc({ commitment: "confirmed" });
@@ -65,7 +84,7 @@ describe("Factory", () => {
     await program.methods
       .pauseFactory()
       .accounts({
-        admin: user1.publicKey,
+        authority: user1.publicKey,
       })
       .rpc();
   });
@@ -74,7 +93,7 @@ describe("Factory", () => {
     await program.methods
       .unpauseFactory()
       .accounts({
-        admin: user1.publicKey,
+        authority: user1.publicKey,
       })
       .rpc();
   });
@@ -84,7 +103,7 @@ describe("Factory", () => {
       await program.methods
         .pauseFactory()
         .accounts({
-          admin: user2.publicKey,
+          authority: user2.publicKey,
         })
         .signers([user2])
         .rpc({ commitment: "confirmed" });
@@ -95,6 +114,223 @@ describe("Factory", () => {
     }
   });
 
+  it("Configures an SPL fee mint!", async () => {
+    feeMint = await createMint(
+      program.provider.connection,
+      user1.payer,
+      user1.publicKey,
+      null,
+      6
+    );
+
+    await program.methods
+      .setFeeMint(new BN(1_000_000), true)
+      .accounts({
+        feeMint,
+        admin: user1.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+
+    const feeMintConfig = await program.account.feeMintConfig.fetch(
+      getFeeMintConfigPDA(program, feeMint)
+    );
+
+    expect(feeMintConfig.mint.toBase58()).to.equal(feeMint.toBase58());
+    expect(feeMintConfig.price.toNumber()).to.equal(1_000_000);
+    expect(feeMintConfig.enabled).to.equal(true);
+  });
+
+  it("Updates the fee schedule and grants a partner discount!", async () => {
+    await program.methods
+      .updateFeeSchedule(new BN(LAMPORTS_FEE), new BN(10), 2_500)
+      .accounts({
+        authority: user1.publicKey,
+      })
+      .rpc();
+
+    await program.methods
+      .setFeeDiscount(10_000)
+      .accounts({
+        wallet: user2.publicKey,
+        admin: user1.publicKey,
+      })
+      .rpc();
+
+    const factoryConfig = await program.account.factoryConfig.fetch(
+      getFactoryPDA(program)
+    );
+    const feeDiscount = await program.account.feeDiscount.fetch(
+      getFeeDiscountPDA(program, user2.publicKey)
+    );
+
+    expect(factoryConfig.token2022SurchargeLamports.toNumber()).to.equal(
+      LAMPORTS_FEE
+    );
+    expect(factoryConfig.volumeDiscountBps).to.equal(2_500);
+    expect(feeDiscount.discountBps).to.equal(10_000);
+  });
+
+  it("Fails to set a discount above 100%!", async () => {
+    try {
+      await program.methods
+        .setFeeDiscount(10_001)
+        .accounts({
+          wallet: user2.publicKey,
+          admin: user1.publicKey,
+        })
+        .rpc();
+      expect.fail("The transaction should have failed");
+    } catch (err: any) {
+      expect(err?.error?.errorCode?.code).to.equal("InvalidBasisPoints");
+    }
+  });
+
+  it("Distributes fees to configured recipients!", async () => {
+    const treasuryPDA = getTreasuryPDA(program);
+    const team = anchor.web3.Keypair.generate().publicKey;
+    const dao = anchor.web3.Keypair.generate().publicKey;
+
+    await program.methods
+      .setFeeRecipients([
+        { recipient: team, bps: 7_000 },
+        { recipient: dao, bps: 3_000 },
+      ])
+      .accounts({
+        admin: user1.publicKey,
+      })
+      .rpc();
+
+    const depositIx = anchor.web3.SystemProgram.transfer({
+      fromPubkey: user2.publicKey,
+      toPubkey: treasuryPDA,
+      lamports: LAMPORTS_FEE,
+    });
+    await program.provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(depositIx),
+      [user2]
+    );
+
+    await program.methods
+      .distributeFees()
+      .remainingAccounts([
+        { pubkey: team, isWritable: true, isSigner: false },
+        { pubkey: dao, isWritable: true, isSigner: false },
+      ])
+      .rpc();
+
+    const connection = program.provider.connection;
+    expect(await connection.getBalance(team)).to.equal(LAMPORTS_FEE * 0.7);
+    expect(await connection.getBalance(dao)).to.equal(LAMPORTS_FEE * 0.3);
+  });
+
+  it("Keeps a share too small to fund a new recipient in the treasury!", async () => {
+    const treasuryPDA = getTreasuryPDA(program);
+    const dust = anchor.web3.Keypair.generate().publicKey;
+    const team = anchor.web3.Keypair.generate().publicKey;
+
+    await program.methods
+      .setFeeRecipients([
+        { recipient: dust, bps: 1 },
+        { recipient: team, bps: 9_999 },
+      ])
+      .accounts({
+        admin: user1.publicKey,
+      })
+      .rpc();
+
+    const depositIx = anchor.web3.SystemProgram.transfer({
+      fromPubkey: user2.publicKey,
+      toPubkey: treasuryPDA,
+      lamports: LAMPORTS_FEE,
+    });
+    await program.provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(depositIx),
+      [user2]
+    );
+
+    const connection = program.provider.connection;
+    const treasuryBalance = await connection.getBalance(treasuryPDA);
+
+    await program.methods
+      .distributeFees()
+      .remainingAccounts([
+        { pubkey: dust, isWritable: true, isSigner: false },
+        { pubkey: team, isWritable: true, isSigner: false },
+      ])
+      .rpc();
+
+    // One basis point of the fee is far below the rent-exempt minimum of an empty account.
+    const dustShare = LAMPORTS_FEE / 10_000;
+    expect(await connection.getBalance(dust)).to.equal(0);
+    expect(await connection.getBalance(team)).to.equal(
+      LAMPORTS_FEE - dustShare
+    );
+    expect(await connection.getBalance(treasuryPDA)).to.equal(
+      treasuryBalance - LAMPORTS_FEE + dustShare
+    );
+  });
+
+  it("Lets a Pauser pause the factory but not withdraw fees!", async () => {
+    const pauserRolePDA = getFactoryRolePDA(program, user2.publicKey, 1);
+
+    await program.methods
+      .grantRole({ pauser: {} })
+      .accountsPartial({
+        factoryRole: pauserRolePDA,
+        holder: user2.publicKey,
+        authority: user1.publicKey,
+        authorityRole: null,
+      })
+      .rpc();
+
+    await program.methods
+      .pauseFactory()
+      .accounts({
+        authority: user2.publicKey,
+        factoryRole: pauserRolePDA,
+      })
+      .signers([user2])
+      .rpc();
+
+    const factoryConfig = await program.account.factoryConfig.fetch(
+      getFactoryPDA(program)
+    );
+    expect(factoryConfig.paused).to.equal(true);
+
+    try {
+      await program.methods
+        .withdrawFees(null)
+        .accounts({
+          destination: user2.publicKey,
+          authority: user2.publicKey,
+          factoryRole: pauserRolePDA,
+        })
+        .signers([user2])
+        .rpc();
+      expect.fail("The transaction should have failed");
+    } catch (err: any) {
+      expect(err?.error?.errorCode?.code).to.equal("Unauthorized");
+    }
+
+    await program.methods
+      .unpauseFactory()
+      .accounts({
+        authority: user1.publicKey,
+        factoryRole: null,
+      })
+      .rpc();
+
+    await program.methods
+      .revokeRole()
+      .accountsPartial({
+        factoryRole: pauserRolePDA,
+        authority: user1.publicKey,
+        authorityRole: null,
+      })
+      .rpc();
+  });
+
   it("Withdraws fees!", async () => {
     const treasuryPDA = getTreasuryPDA(program);
     const rentExemptMinimum =
@@ -118,9 +354,10 @@ describe("Factory", () => {
     );
 
     await program.methods
-      .withdrawFees()
+      .withdrawFees(null)
       .accounts({
-        admin: user1.publicKey,
+        destination: user1.publicKey,
+        authority: user1.publicKey,
       })
       .rpc();
 
@@ -134,4 +371,490 @@ describe("Factory", () => {
     expect(finalTreasuryBalance).to.equal(rentExemptMinimum);
     expect(finalAdminBalance).to.be.greaterThan(initialAdminBalance);
   });
+
+  it("Fails to withdraw fees from an empty treasury!", async () => {
+    try {
+      await program.methods
+        .withdrawFees(null)
+        .accounts({
+          destination: user1.publicKey,
+          authority: user1.publicKey,
+        })
+        .rpc();
+      expect.fail("The transaction should have failed");
+    } catch (err: any) {
+      expect(err?.error?.errorCode?.code).to.equal(
+        "InsufficientFundsToWithdraw"
+      );
+    }
+  });
+
+  it("Withdraws a specific amount to a cold wallet!", async () => {
+    const treasuryPDA = getTreasuryPDA(program);
+    const coldWallet = anchor.web3.Keypair.generate().publicKey;
+
+    const depositIx = anchor.web3.SystemProgram.transfer({
+      fromPubkey: user2.publicKey,
+      toPubkey: treasuryPDA,
+      lamports: LAMPORTS_FEE,
+    });
+    await program.provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(depositIx),
+      [user2]
+    );
+
+    await program.methods
+      .withdrawFees(new BN(LAMPORTS_FEE / 2))
+      .accounts({
+        destination: coldWallet,
+        authority: user1.publicKey,
+      })
+      .rpc();
+
+    expect(await program.provider.connection.getBalance(coldWallet)).to.equal(
+      LAMPORTS_FEE / 2
+    );
+  });
+
+  it("Fails to withdraw more than the withdrawable amount!", async () => {
+    try {
+      await program.methods
+        .withdrawFees(new BN(LAMPORTS_FEE))
+        .accounts({
+          destination: user1.publicKey,
+          authority: user1.publicKey,
+        })
+        .rpc();
+      expect.fail("The transaction should have failed");
+    } catch (err: any) {
+      expect(err?.error?.errorCode?.code).to.equal(
+        "InsufficientFundsToWithdraw"
+      );
+    }
+  });
+
+  it("Timelocks fee changes until the delay has passed!", async () => {
+    const factoryPDA = getFactoryPDA(program);
+    const treasuryPDA = getTreasuryPDA(program);
+    const coldWallet = anchor.web3.Keypair.generate().publicKey;
+
+    await program.methods
+      .configureTimelock(new BN(4), new BN(LAMPORTS_FEE / 2))
+      .accounts({
+        admin: user1.publicKey,
+      })
+      .rpc();
+
+    try {
+      await program.methods
+        .updateCreationFee(new BN(LAMPORTS_FEE * 100))
+        .accounts({
+          authority: user1.publicKey,
+        })
+        .rpc();
+      expect.fail("The transaction should have failed");
+    } catch (err: any) {
+      expect(err?.error?.errorCode?.code).to.equal("TimelockRequired");
+    }
+
+    try {
+      await program.methods
+        .setFeeRecipients([])
+        .accounts({
+          admin: user1.publicKey,
+        })
+        .rpc();
+      expect.fail("The transaction should have failed");
+    } catch (err: any) {
+      expect(err?.error?.errorCode?.code).to.equal("TimelockRequired");
+    }
+
+    try {
+      await program.methods
+        .setFeeMint(new BN(2_000_000), true)
+        .accounts({
+          feeMint,
+          admin: user1.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+      expect.fail("The transaction should have failed");
+    } catch (err: any) {
+      expect(err?.error?.errorCode?.code).to.equal("TimelockRequired");
+    }
+
+    const depositIx = anchor.web3.SystemProgram.transfer({
+      fromPubkey: user2.publicKey,
+      toPubkey: treasuryPDA,
+      lamports: LAMPORTS_FEE,
+    });
+    await program.provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(depositIx),
+      [user2]
+    );
+
+    // The threshold covers every direct withdrawal in the window, not each one separately.
+    await program.methods
+      .withdrawFees(new BN(LAMPORTS_FEE / 2))
+      .accounts({
+        destination: coldWallet,
+        authority: user1.publicKey,
+      })
+      .rpc();
+
+    try {
+      await program.methods
+        .withdrawFees(new BN(LAMPORTS_FEE / 2))
+        .accounts({
+          destination: coldWallet,
+          authority: user1.publicKey,
+        })
+        .rpc();
+      expect.fail("The transaction should have failed");
+    } catch (err: any) {
+      expect(err?.error?.errorCode?.code).to.equal("TimelockRequired");
+    }
+
+    const { nextActionId } = await program.account.factoryConfig.fetch(
+      factoryPDA
+    );
+    const setFeeMintPDA = getPendingActionPDA(program, nextActionId);
+    const setFeeRecipientsPDA = getPendingActionPDA(
+      program,
+      nextActionId.addn(1)
+    );
+    const configureTimelockPDA = getPendingActionPDA(
+      program,
+      nextActionId.addn(2)
+    );
+
+    await program.methods
+      .queueAdminAction({
+        setFeeMint: {
+          mint: feeMint,
+          price: new BN(2_000_000),
+          enabled: false,
+        },
+      })
+      .accounts({
+        admin: user1.publicKey,
+      })
+      .rpc();
+
+    await program.methods
+      .queueAdminAction({ setFeeRecipients: { recipients: [] } })
+      .accounts({
+        admin: user1.publicKey,
+      })
+      .rpc();
+
+    await program.methods
+      .queueAdminAction({
+        configureTimelock: {
+          delaySeconds: new BN(0),
+          withdrawalThreshold: new BN(0),
+        },
+      })
+      .accounts({
+        admin: user1.publicKey,
+      })
+      .rpc();
+
+    try {
+      await program.methods
+        .executeAdminAction()
+        .accounts({
+          pendingAction: configureTimelockPDA,
+          destination: null,
+          feeMintConfig: null,
+          admin: user1.publicKey,
+          tokenProgram: null,
+        })
+        .rpc();
+      expect.fail("The transaction should have failed");
+    } catch (err: any) {
+      expect(err?.error?.errorCode?.code).to.equal("TimelockNotElapsed");
+    }
+
+    await new Promise((resolve) => setTimeout(resolve, 6000));
+
+    await program.methods
+      .executeAdminAction()
+      .accounts({
+        pendingAction: setFeeMintPDA,
+        destination: null,
+        feeMintConfig: getFeeMintConfigPDA(program, feeMint),
+        admin: user1.publicKey,
+        tokenProgram: null,
+      })
+      .rpc();
+
+    for (const pendingAction of [setFeeRecipientsPDA, configureTimelockPDA]) {
+      await program.methods
+        .executeAdminAction()
+        .accounts({
+          pendingAction,
+          destination: null,
+          feeMintConfig: null,
+          admin: user1.publicKey,
+          tokenProgram: null,
+        })
+        .rpc();
+    }
+
+    const feeMintConfig = await program.account.feeMintConfig.fetch(
+      getFeeMintConfigPDA(program, feeMint)
+    );
+    expect(feeMintConfig.price.toNumber()).to.equal(2_000_000);
+    expect(feeMintConfig.enabled).to.equal(false);
+
+    const factoryConfig = await program.account.factoryConfig.fetch(factoryPDA);
+    expect(factoryConfig.feeRecipients).to.be.empty;
+    expect(factoryConfig.timelockDelaySeconds.toNumber()).to.equal(0);
+
+    try {
+      await program.methods
+        .queueAdminAction({
+          updateCreationFee: { creationFeeLamports: new BN(LAMPORTS_FEE) },
+        })
+        .accounts({
+          admin: user1.publicKey,
+        })
+        .rpc();
+      expect.fail("The transaction should have failed");
+    } catch (err: any) {
+      expect(err?.error?.errorCode?.code).to.equal("TimelockNotEnabled");
+    }
+  });
+
+  it("Requires multisig approval once admin signers are set!", async () => {
+    const factoryPDA = getFactoryPDA(program);
+    const pauserRolePDA = getFactoryRolePDA(program, user2.publicKey, 1);
+
+    const propose = async (action: any) => {
+      const { nextProposalId } = await program.account.factoryConfig.fetch(
+        factoryPDA
+      );
+      await program.methods
+        .createProposal(action)
+        .accounts({
+          proposer: user1.publicKey,
+        })
+        .rpc();
+      return getProposalPDA(program, nextProposalId);
+    };
+    const approve = (proposal: anchor.web3.PublicKey) =>
+      program.methods
+        .approveProposal()
+        .accounts({
+          proposal,
+          approver: user2.publicKey,
+        })
+        .signers([user2])
+        .rpc();
+    const execute = (
+      proposal: anchor.web3.PublicKey,
+      target: anchor.web3.PublicKey | null = null
+    ) =>
+      program.methods
+        .executeProposal()
+        .accounts({
+          proposal,
+          proposer: user1.publicKey,
+          destination: null,
+          target,
+          feeMintConfig: null,
+          executor: user1.publicKey,
+          tokenProgram: null,
+        })
+        .rpc();
+
+    await program.methods
+      .setAdminSigners([user1.publicKey, user2.publicKey], 2)
+      .accounts({
+        admin: user1.publicKey,
+      })
+      .rpc();
+
+    // Under multisig the admin key no longer implicitly holds every role.
+    try {
+      await program.methods
+        .pauseFactory()
+        .accounts({
+          authority: user1.publicKey,
+        })
+        .rpc();
+      expect.fail("The transaction should have failed");
+    } catch (err: any) {
+      expect(err?.error?.errorCode?.code).to.equal("Unauthorized");
+    }
+
+    try {
+      await program.methods
+        .updateReferralFee(100)
+        .accounts({
+          admin: user1.publicKey,
+        })
+        .rpc();
+      expect.fail("The transaction should have failed");
+    } catch (err: any) {
+      expect(err?.error?.errorCode?.code).to.equal("MultisigRequired");
+    }
+
+    // Enable the timelock so later proposals have to wait it out.
+    const configureTimelock = await propose({
+      configureTimelock: {
+        delaySeconds: new BN(2),
+        withdrawalThreshold: new BN(0),
+      },
+    });
+    await approve(configureTimelock);
+    await execute(configureTimelock);
+
+    const grantRole = await propose({
+      grantRole: { holder: user2.publicKey, role: { pauser: {} } },
+    });
+    const updateReferralFee = await propose({
+      updateReferralFee: { referralFeeBps: 100 },
+    });
+    const disableTimelock = await propose({
+      configureTimelock: {
+        delaySeconds: new BN(0),
+        withdrawalThreshold: new BN(0),
+      },
+    });
+    const removeSigners = await propose({
+      setAdminSigners: { signers: [], threshold: 0 },
+    });
+
+    try {
+      await execute(removeSigners);
+      expect.fail("The transaction should have failed");
+    } catch (err: any) {
+      expect(err?.error?.errorCode?.code).to.equal("ThresholdNotMet");
+    }
+
+    for (const proposal of [
+      grantRole,
+      updateReferralFee,
+      disableTimelock,
+      removeSigners,
+    ]) {
+      await approve(proposal);
+    }
+
+    try {
+      await execute(grantRole, pauserRolePDA);
+      expect.fail("The transaction should have failed");
+    } catch (err: any) {
+      expect(err?.error?.errorCode?.code).to.equal("TimelockNotElapsed");
+    }
+
+    await new Promise((resolve) => setTimeout(resolve, 4000));
+
+    await execute(grantRole, pauserRolePDA);
+
+    // An explicit Pauser grant can still pause directly, but unpausing needs a proposal,
+    // which is not held for the timelock delay.
+    await program.methods
+      .pauseFactory()
+      .accounts({
+        authority: user2.publicKey,
+        factoryRole: pauserRolePDA,
+      })
+      .signers([user2])
+      .rpc();
+
+    try {
+      await program.methods
+        .unpauseFactory()
+        .accounts({
+          authority: user2.publicKey,
+          factoryRole: pauserRolePDA,
+        })
+        .signers([user2])
+        .rpc();
+      expect.fail("The transaction should have failed");
+    } catch (err: any) {
+      expect(err?.error?.errorCode?.code).to.equal("MultisigRequired");
+    }
+
+    const unpause = await propose({ unpause: {} });
+    await approve(unpause);
+    await execute(unpause);
+    expect(
+      (await program.account.factoryConfig.fetch(factoryPDA)).paused
+    ).to.equal(false);
+
+    await execute(updateReferralFee);
+    await execute(disableTimelock);
+    await execute(removeSigners);
+
+    const factoryRole = await program.account.factoryRole.fetch(pauserRolePDA);
+    expect(factoryRole.holder.toBase58()).to.equal(user2.publicKey.toBase58());
+
+    const factoryConfig = await program.account.factoryConfig.fetch(factoryPDA);
+    expect(factoryConfig.referralFeeBps).to.equal(100);
+    expect(factoryConfig.timelockDelaySeconds.toNumber()).to.equal(0);
+    expect(factoryConfig.adminSigners).to.be.empty;
+
+    await program.methods
+      .revokeRole()
+      .accountsPartial({
+        factoryRole: pauserRolePDA,
+        authority: user1.publicKey,
+        authorityRole: null,
+      })
+      .rpc();
+  });
+
+  it("Blocks a guardian-paused module until it is unpaused!", async () => {
+    const PAUSE_FACTORY = 1;
+
+    await program.methods
+      .guardianPause(PAUSE_FACTORY)
+      .accounts({
+        guardian: user1.publicKey,
+      })
+      .rpc();
+
+    try {
+      await program.methods
+        .updateCreationFee(new BN(LAMPORTS_FEE))
+        .accounts({
+          authority: user1.publicKey,
+          factoryRole: null,
+        })
+        .rpc();
+      expect.fail("The transaction should have failed");
+    } catch (err: any) {
+      expect(err?.error?.errorCode?.code).to.equal("ModulePaused");
+    }
+
+    await program.methods
+      .guardianUnpause(PAUSE_FACTORY)
+      .accounts({
+        signer: user1.publicKey,
+      })
+      .rpc();
+  });
+
+  it("Rejects migrating a config that is already current!", async () => {
+    const factoryConfig = await program.account.factoryConfig.fetch(
+      getFactoryPDA(program)
+    );
+    expect(factoryConfig.version).to.equal(1);
+
+    try {
+      await program.methods
+        .migrateFactoryConfig()
+        .accounts({
+          admin: user1.publicKey,
+        })
+        .rpc();
+      expect.fail("The transaction should have failed");
+    } catch (err: any) {
+      expect(err?.error?.errorCode?.code).to.equal("UnsupportedConfigLayout");
+    }
+  });
 });
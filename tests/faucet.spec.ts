@@ -1,16 +1,31 @@
 import { BN } from "bn.js";
 import { expect } from "chai";
 import * as anchor from "@coral-xyz/anchor";
-import { airdropSol, user1, user2, program } from "./setup";
+import {
+  airdropSol,
+  createTransferFeeMint,
+  initializeProgramState,
+  user1,
+  user2,
+  program,
+} from "./setup";
 import {
   createMint,
   getAssociatedTokenAddress,
+  getAssociatedTokenAddressSync,
   getOrCreateAssociatedTokenAccount,
   mintTo,
+  transferChecked,
+  TOKEN_2022_PROGRAM_ID,
   TOKEN_PROGRAM_ID,
   getAccount,
 } from "@solana/spl-token";
-import { getFaucetPDA } from "./pdas";
+import {
+  getFaucetDepositorPDA,
+  getFaucetPDA,
+  getFaucetPoolCounterPDA,
+  getFaucetRecipientPDA,
+} from "./pdas";
 
 describe("Faucet", () => {
   let mint: anchor.web3.PublicKey;
@@ -22,6 +37,7 @@ describe("Faucet", () => {
     // Airdrop some SOL to the user1 before tests
     await airdropSol(user1.publicKey, 2);
     await airdropSol(user2.publicKey, 2);
+    await initializeProgramState();
 
     mint = await createMint(
       program.provider.connection,
@@ -49,6 +65,55 @@ describe("Faucet", () => {
     );
   });
 
+  // A funded wallet with a token account that has never claimed from the faucet.
+  async function newClaimer() {
+    const claimer = anchor.web3.Keypair.generate();
+    await airdropSol(claimer.publicKey, 1);
+    await getOrCreateAssociatedTokenAccount(
+      program.provider.connection,
+      claimer,
+      mint,
+      claimer.publicKey
+    );
+    return claimer;
+  }
+
+  function claimFrom(recipient: anchor.web3.Keypair) {
+    return program.methods.claimFromFaucet().accounts({
+      mint: mint,
+      recipient: recipient.publicKey,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    });
+  }
+
+  function claimWithReferrer(
+    recipient: anchor.web3.Keypair,
+    referrer: anchor.web3.PublicKey,
+    referrerRecordOwner = referrer
+  ) {
+    return program.methods.claimFromFaucet().accounts({
+      mint: mint,
+      recipient: recipient.publicKey,
+      referrer,
+      referrerData: getFaucetRecipientPDA(program, referrerRecordOwner),
+      referrerAta: getAssociatedTokenAddressSync(mint, referrer),
+      tokenProgram: TOKEN_PROGRAM_ID,
+    });
+  }
+
+  async function waitForNextEpoch() {
+    const connection = program.provider.connection;
+    const { epoch } = await connection.getEpochInfo();
+    while ((await connection.getEpochInfo()).epoch === epoch) {
+      await new Promise((resolve) => setTimeout(resolve, 500));
+    }
+  }
+
+  async function tokenBalance(owner: anchor.web3.PublicKey) {
+    const ata = await getAssociatedTokenAddress(mint, owner);
+    return (await getAccount(program.provider.connection, ata)).amount;
+  }
+
   it("Initialized!", async () => {
     faucetPda = getFaucetPDA(program);
     treasuryAta = await getAssociatedTokenAddress(mint, faucetPda, true);
@@ -76,6 +141,7 @@ describe("Faucet", () => {
     await program.methods
       .depositToFaucet(DEPOSIT_AMOUNT)
       .accounts({
+        mint: mint,
         depositor: user1.publicKey,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
@@ -87,6 +153,23 @@ describe("Faucet", () => {
     );
 
     expect(treasuryAccount.amount).to.eql(BigInt(DEPOSIT_AMOUNT.toString()));
+
+    const depositorRecordPda = getFaucetDepositorPDA(
+      program,
+      faucetPda,
+      user1.publicKey
+    );
+    const depositorRecord = await program.account.faucetDepositorRecord.fetch(
+      depositorRecordPda
+    );
+    const faucetConfig = await program.account.faucetConfig.fetch(faucetPda);
+
+    expect(depositorRecord.totalDeposited.toString()).to.equal(
+      DEPOSIT_AMOUNT.toString()
+    );
+    expect(faucetConfig.totalDeposited.toString()).to.equal(
+      DEPOSIT_AMOUNT.toString()
+    );
   });
 
   it("Withdraw tokens from faucet!", async () => {
@@ -100,7 +183,9 @@ describe("Faucet", () => {
     await program.methods
       .withdrawFromFaucet(WITHDRAW_AMOUNT)
       .accounts({
-        recipient: user1.publicKey,
+        mint: mint,
+        owner: user1.publicKey,
+        destination: user1.publicKey,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
       .rpc();
@@ -141,6 +226,7 @@ describe("Faucet", () => {
     await program.methods
       .claimFromFaucet()
       .accounts({
+        mint: mint,
         recipient: user2.publicKey,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
@@ -165,4 +251,817 @@ describe("Faucet", () => {
       BigInt(user2AtaInfoBefore.amount + BigInt(1_000_000_000))
     );
   });
+
+  it("Relayer sponsors a claim for a wallet without SOL!", async () => {
+    const freshWallet = anchor.web3.Keypair.generate();
+
+    await program.methods
+      .claimSponsoredFromFaucet()
+      .accounts({
+        mint: mint,
+        recipient: freshWallet.publicKey,
+        feePayer: user1.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([freshWallet])
+      .rpc();
+
+    const freshWalletAta = await getAssociatedTokenAddress(
+      mint,
+      freshWallet.publicKey
+    );
+    const freshWalletAtaInfo = await getAccount(
+      program.provider.connection,
+      freshWalletAta
+    );
+
+    expect(freshWalletAtaInfo.amount).to.eql(BigInt(1_000_000_000));
+
+    // Without rent sponsorship the relayer funded the record and gets the rent back on close.
+    const recipientData = await program.account.faucetRecipientData.fetch(
+      getFaucetRecipientPDA(program, freshWallet.publicKey)
+    );
+    expect(recipientData.rentPayer.toBase58()).to.equal(
+      user1.publicKey.toBase58()
+    );
+    expect(recipientData.version).to.equal(1);
+  });
+
+  it("Migrates a legacy recipient record in place!", async () => {
+    // Owner of the fixture loaded from tests/fixtures/legacy_faucet_recipient.json.
+    const legacyRecipient = new anchor.web3.PublicKey(
+      "F5ZCKmfrkxsM1fJD1KcsavjKb47S3HwSYKduxGNQ89YC"
+    );
+    const recipientDataPDA = getFaucetRecipientPDA(program, legacyRecipient);
+    const legacyAccount = await program.provider.connection.getAccountInfo(
+      recipientDataPDA
+    );
+    expect(legacyAccount?.data.length).to.equal(16);
+
+    await program.methods
+      .migrateRecipientData()
+      .accounts({
+        recipient: legacyRecipient,
+        payer: user1.publicKey,
+      })
+      .rpc({ commitment: "confirmed" });
+
+    const recipientData = await program.account.faucetRecipientData.fetch(
+      recipientDataPDA,
+      "confirmed"
+    );
+    expect(recipientData.version).to.equal(1);
+    expect(recipientData.lastClaimedAt.toNumber()).to.equal(1_700_000_000);
+    expect(recipientData.rentPayer.toBase58()).to.equal(
+      legacyRecipient.toBase58()
+    );
+  });
+
+  it("Refuses to migrate a recipient record that is already current!", async () => {
+    try {
+      await program.methods
+        .migrateRecipientData()
+        .accounts({
+          recipient: user2.publicKey,
+          payer: user1.publicKey,
+        })
+        .rpc({ commitment: "confirmed" });
+      expect.fail("The transaction should have failed");
+    } catch (err: any) {
+      const code = err?.error?.errorCode?.code;
+      expect(code).to.equal("UnsupportedRecordLayout");
+    }
+  });
+
+  it("Fails to close recipient data before cooldown elapses!", async () => {
+    try {
+      await program.methods
+        .closeRecipientData()
+        .accounts({
+          recipient: user2.publicKey,
+          rentPayer: user2.publicKey,
+        })
+        .signers([user2])
+        .rpc({ commitment: "confirmed" });
+      expect.fail("The transaction should have failed");
+    } catch (err: any) {
+      const code = err?.error?.errorCode?.code;
+      expect(code).to.equal("CooldownNotElapsed");
+    }
+  });
+
+  it("Claims again once a slot-based cooldown elapses!", async () => {
+    await program.methods
+      .setCooldownMode({ slot: {} }, new BN(1))
+      .accounts({
+        authority: user1.publicKey,
+      })
+      .rpc();
+
+    const user2Ata = await getAssociatedTokenAddress(mint, user2.publicKey);
+    const user2AtaInfoBefore = await getAccount(
+      program.provider.connection,
+      user2Ata
+    );
+
+    await program.methods
+      .claimFromFaucet()
+      .accounts({
+        mint: mint,
+        recipient: user2.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([user2])
+      .rpc();
+
+    const user2AtaInfoAfter = await getAccount(
+      program.provider.connection,
+      user2Ata
+    );
+
+    expect(user2AtaInfoAfter.amount).to.eql(
+      user2AtaInfoBefore.amount + BigInt(1_000_000_000)
+    );
+  });
+
+  it("Operator can pause the faucet but not withdraw!", async () => {
+    await program.methods
+      .setFaucetOperator(user2.publicKey, new BN(500_000_000), new BN(3600))
+      .accounts({
+        owner: user1.publicKey,
+      })
+      .rpc();
+
+    await program.methods
+      .pauseFaucet()
+      .accounts({
+        authority: user2.publicKey,
+      })
+      .signers([user2])
+      .rpc();
+
+    const faucetConfig = await program.account.faucetConfig.fetch(faucetPda);
+    expect(faucetConfig.paused).to.equal(true);
+
+    try {
+      await program.methods
+        .withdrawFromFaucet(new BN(1))
+        .accounts({
+          mint: mint,
+          owner: user2.publicKey,
+          destination: user2.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([user2])
+        .rpc({ commitment: "confirmed" });
+      expect.fail("The transaction should have failed");
+    } catch (err: any) {
+      const code = err?.error?.errorCode?.code;
+      expect(code).to.equal("Unauthorized");
+    }
+
+    await program.methods
+      .unpauseFaucet()
+      .accounts({
+        authority: user2.publicKey,
+      })
+      .signers([user2])
+      .rpc();
+  });
+
+  it("Operator can only tune claims within the owner's limits!", async () => {
+    const outOfBounds = [
+      [new BN(1_000_000_000), new BN(3600)],
+      [new BN(500_000_000), new BN(60)],
+    ];
+    for (const [amount, cooldown] of outOfBounds) {
+      try {
+        await program.methods
+          .updateFaucetSettings(amount, cooldown)
+          .accounts({
+            authority: user2.publicKey,
+          })
+          .signers([user2])
+          .rpc({ commitment: "confirmed" });
+        expect.fail("The transaction should have failed");
+      } catch (err: any) {
+        const code = err?.error?.errorCode?.code;
+        expect(code).to.equal("OperatorLimitExceeded");
+      }
+    }
+
+    // A zero limit removes the epoch cap, which only the owner may do.
+    try {
+      await program.methods
+        .setEpochClaimLimit(new BN(0))
+        .accounts({
+          authority: user2.publicKey,
+        })
+        .signers([user2])
+        .rpc({ commitment: "confirmed" });
+      expect.fail("The transaction should have failed");
+    } catch (err: any) {
+      const code = err?.error?.errorCode?.code;
+      expect(code).to.equal("OperatorLimitExceeded");
+    }
+
+    await program.methods
+      .updateFaucetSettings(new BN(500_000_000), new BN(7200))
+      .accounts({
+        authority: user2.publicKey,
+      })
+      .signers([user2])
+      .rpc();
+    let faucetConfig = await program.account.faucetConfig.fetch(faucetPda);
+    expect(faucetConfig.allowedClaimAmount.toString()).to.equal("500000000");
+
+    // The owner is not bound by the operator limits.
+    await program.methods
+      .updateFaucetSettings(new BN(1_000_000_000), new BN(3600))
+      .accounts({
+        authority: user1.publicKey,
+      })
+      .rpc();
+    faucetConfig = await program.account.faucetConfig.fetch(faucetPda);
+    expect(faucetConfig.allowedClaimAmount.toString()).to.equal("1000000000");
+  });
+
+  it("Sponsors receive pro-rata shares in pool mode!", async () => {
+    await program.methods
+      .setSponsoredPool(true, new BN(10))
+      .accountsPartial({
+        owner: user1.publicKey,
+        treasuryAta,
+      })
+      .rpc();
+
+    await program.methods
+      .depositToFaucet(new BN(3_000_000_000))
+      .accounts({
+        mint: mint,
+        depositor: user1.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+    await program.methods
+      .depositToFaucet(new BN(1_000_000_000))
+      .accounts({
+        mint: mint,
+        depositor: user2.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([user2])
+      .rpc();
+
+    // Both deposits enter at the same share price.
+    const user1Record = await program.account.faucetDepositorRecord.fetch(
+      getFaucetDepositorPDA(program, faucetPda, user1.publicKey)
+    );
+    const user2Record = await program.account.faucetDepositorRecord.fetch(
+      getFaucetDepositorPDA(program, faucetPda, user2.publicKey)
+    );
+    const faucetConfig = await program.account.faucetConfig.fetch(faucetPda);
+    expect(user1Record.shares.toString()).to.equal("3000000000");
+    expect(user2Record.shares.toString()).to.equal("1000000000");
+    expect(faucetConfig.totalShares.toString()).to.equal("4000000000");
+
+    try {
+      await program.methods
+        .withdrawFromFaucet(new BN(1))
+        .accounts({
+          mint: mint,
+          owner: user1.publicKey,
+          destination: user1.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc({ commitment: "confirmed" });
+      expect.fail("The transaction should have failed");
+    } catch (err: any) {
+      const code = err?.error?.errorCode?.code;
+      expect(code).to.equal("SponsoredPoolActive");
+    }
+  });
+
+  it("Rejects pool deposits too small to mint a share!", async () => {
+    // Donating straight to the treasury pushes the share price above one token.
+    await transferChecked(
+      program.provider.connection,
+      user1.payer,
+      user1Ata,
+      mint,
+      treasuryAta,
+      user1.publicKey,
+      BigInt(2_000_000_000),
+      6
+    );
+
+    try {
+      await program.methods
+        .depositToFaucet(new BN(1))
+        .accounts({
+          mint: mint,
+          depositor: user1.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc({ commitment: "confirmed" });
+      expect.fail("The transaction should have failed");
+    } catch (err: any) {
+      const code = err?.error?.errorCode?.code;
+      expect(code).to.equal("DepositTooSmall");
+    }
+  });
+
+  it("Locks sponsor shares, then refunds them pro-rata!", async () => {
+    // The owner cannot extend the lock on deposits already made.
+    try {
+      await program.methods
+        .setSponsoredPool(true, new BN(3600))
+        .accountsPartial({
+          owner: user1.publicKey,
+          treasuryAta,
+        })
+        .rpc();
+      expect.fail("The transaction should have failed");
+    } catch (err: any) {
+      const code = err?.error?.errorCode?.code;
+      expect(code).to.equal("InvalidSponsorLock");
+    }
+
+    try {
+      await program.methods
+        .withdrawSponsorShare()
+        .accounts({
+          mint: mint,
+          depositor: user2.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([user2])
+        .rpc({ commitment: "confirmed" });
+      expect.fail("The transaction should have failed");
+    } catch (err: any) {
+      const code = err?.error?.errorCode?.code;
+      expect(code).to.equal("SponsorLockActive");
+    }
+
+    // A 1,000-token claim shrinks the pool to 5,000 tokens backing 4,000 tokens of shares.
+    await program.methods
+      .claimFromFaucet()
+      .accounts({
+        mint: mint,
+        recipient: user2.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([user2])
+      .rpc();
+
+    await new Promise((resolve) => setTimeout(resolve, 11000));
+
+    const user2Ata = await getAssociatedTokenAddress(mint, user2.publicKey);
+    const user2AtaBefore = await getAccount(
+      program.provider.connection,
+      user2Ata
+    );
+    await program.methods
+      .withdrawSponsorShare()
+      .accounts({
+        mint: mint,
+        depositor: user2.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([user2])
+      .rpc();
+    const user2AtaAfter = await getAccount(
+      program.provider.connection,
+      user2Ata
+    );
+    expect(user2AtaAfter.amount - user2AtaBefore.amount).to.eql(
+      BigInt(1_250_000_000)
+    );
+
+    const user1AtaBefore = await getAccount(
+      program.provider.connection,
+      user1Ata
+    );
+    await program.methods
+      .withdrawSponsorShare()
+      .accounts({
+        mint: mint,
+        depositor: user1.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+    const user1AtaAfter = await getAccount(
+      program.provider.connection,
+      user1Ata
+    );
+    expect(user1AtaAfter.amount - user1AtaBefore.amount).to.eql(
+      BigInt(3_750_000_000)
+    );
+
+    // With every sponsor out, the owner can leave pool mode.
+    await program.methods
+      .setSponsoredPool(false, new BN(0))
+      .accountsPartial({
+        owner: user1.publicKey,
+        treasuryAta,
+      })
+      .rpc();
+  });
+
+  it("Drip mode accrues claims up to the cap!", async () => {
+    const DRIP_CAP = BigInt(300_000_000);
+
+    await program.methods
+      .depositToFaucet(new BN(2_000_000_000))
+      .accounts({
+        mint: mint,
+        depositor: user1.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+    await program.methods
+      .configureDrip(new BN(100_000_000), new BN(DRIP_CAP.toString()))
+      .accounts({
+        authority: user1.publicKey,
+      })
+      .rpc();
+
+    // A wallet that never claimed starts with a full bucket.
+    const claimer = await newClaimer();
+    await claimFrom(claimer).signers([claimer]).rpc();
+    expect(await tokenBalance(claimer.publicKey)).to.eql(DRIP_CAP);
+
+    // Both claims in one transaction see the same clock, so the second finds nothing accrued.
+    try {
+      await claimFrom(claimer)
+        .preInstructions([await claimFrom(claimer).instruction()])
+        .signers([claimer])
+        .rpc({ commitment: "confirmed" });
+      expect.fail("The transaction should have failed");
+    } catch (err: any) {
+      const code = err?.error?.errorCode?.code;
+      expect(code).to.equal("NothingToClaim");
+    }
+
+    // Four seconds at the drip rate would exceed the cap, so the claim is capped.
+    await new Promise((resolve) => setTimeout(resolve, 4000));
+    await claimFrom(claimer).signers([claimer]).rpc();
+    expect(await tokenBalance(claimer.publicKey)).to.eql(DRIP_CAP * BigInt(2));
+
+    await program.methods
+      .configureDrip(new BN(0), new BN(0))
+      .accounts({
+        authority: user1.publicKey,
+      })
+      .rpc();
+  });
+
+  it("Caps claims per epoch and resets the count each epoch!", async () => {
+    await program.methods
+      .depositToFaucet(new BN(3_000_000_000))
+      .accounts({
+        mint: mint,
+        depositor: user1.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+    await program.methods
+      .setEpochClaimLimit(new BN(1))
+      .accounts({
+        authority: user1.publicKey,
+      })
+      .rpc();
+
+    // Start from a fresh epoch so earlier claims do not count against the limit.
+    const claimer = await newClaimer();
+    await waitForNextEpoch();
+    await claimFrom(claimer).signers([claimer]).rpc();
+
+    try {
+      await claimFrom(user2).signers([user2]).rpc({ commitment: "confirmed" });
+      expect.fail("The transaction should have failed");
+    } catch (err: any) {
+      const code = err?.error?.errorCode?.code;
+      expect(code).to.equal("EpochClaimLimitReached");
+    }
+
+    await waitForNextEpoch();
+    const balanceBefore = await tokenBalance(user2.publicKey);
+    await claimFrom(user2).signers([user2]).rpc();
+    expect(await tokenBalance(user2.publicKey)).to.eql(
+      balanceBefore + BigInt(1_000_000_000)
+    );
+
+    await program.methods
+      .setEpochClaimLimit(new BN(0))
+      .accounts({
+        authority: user1.publicKey,
+      })
+      .rpc();
+  });
+
+  it("Pays a referral bonus at most once per referrer cooldown!", async () => {
+    const BONUS = BigInt(100_000_000);
+    await program.methods
+      .configureReferrals(new BN(BONUS.toString()), new BN(3600))
+      .accounts({
+        authority: user1.publicKey,
+      })
+      .rpc();
+
+    // user2 has already claimed, so it may refer new recipients.
+    const referee = await newClaimer();
+    const referrerBefore = await tokenBalance(user2.publicKey);
+    await claimWithReferrer(referee, user2.publicKey).signers([referee]).rpc();
+
+    expect(await tokenBalance(referee.publicKey)).to.eql(
+      BigInt(1_000_000_000)
+    );
+    expect(await tokenBalance(user2.publicKey)).to.eql(referrerBefore + BONUS);
+    const refereeData = await program.account.faucetRecipientData.fetch(
+      getFaucetRecipientPDA(program, referee.publicKey)
+    );
+    expect(refereeData.referrer.toBase58()).to.equal(
+      user2.publicKey.toBase58()
+    );
+
+    // A second referral within the cooldown is recorded but not rewarded.
+    const secondReferee = await newClaimer();
+    await claimWithReferrer(secondReferee, user2.publicKey)
+      .signers([secondReferee])
+      .rpc();
+    expect(await tokenBalance(user2.publicKey)).to.eql(referrerBefore + BONUS);
+    const referrerData = await program.account.faucetRecipientData.fetch(
+      getFaucetRecipientPDA(program, user2.publicKey)
+    );
+    expect(referrerData.referralCount.toString()).to.equal("2");
+  });
+
+  it("Rejects self-referrals!", async () => {
+    const claimer = await newClaimer();
+
+    try {
+      await claimWithReferrer(claimer, claimer.publicKey, user2.publicKey)
+        .signers([claimer])
+        .rpc({ commitment: "confirmed" });
+      expect.fail("The transaction should have failed");
+    } catch (err: any) {
+      const code = err?.error?.errorCode?.code;
+      expect(code).to.equal("SelfReferral");
+    }
+  });
+
+  it("Keeps referral records open so referrals cannot be replayed!", async () => {
+    // Closing and re-opening would let the referrer be referred back, forming a cycle.
+    try {
+      await program.methods
+        .closeRecipientData()
+        .accounts({
+          recipient: user2.publicKey,
+          rentPayer: user2.publicKey,
+        })
+        .signers([user2])
+        .rpc({ commitment: "confirmed" });
+      expect.fail("The transaction should have failed");
+    } catch (err: any) {
+      const code = err?.error?.errorCode?.code;
+      expect(code).to.equal("ReferralRecordLocked");
+    }
+  });
+
+  it("Withdraws the whole treasury to a wallet without a token account!", async () => {
+    const coldWallet = anchor.web3.Keypair.generate();
+    const coldWalletAta = await getAssociatedTokenAddress(
+      mint,
+      coldWallet.publicKey
+    );
+    expect(await program.provider.connection.getAccountInfo(coldWalletAta)).to
+      .be.null;
+
+    const treasuryBefore = await getAccount(
+      program.provider.connection,
+      treasuryAta
+    );
+    expect(treasuryBefore.amount > BigInt(0)).to.equal(true);
+
+    await program.methods
+      .withdrawAllFromFaucet()
+      .accounts({
+        mint: mint,
+        owner: user1.publicKey,
+        destination: coldWallet.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+
+    expect(await tokenBalance(coldWallet.publicKey)).to.eql(
+      treasuryBefore.amount
+    );
+    const treasuryAfter = await getAccount(
+      program.provider.connection,
+      treasuryAta
+    );
+    expect(treasuryAfter.amount).to.eql(BigInt(0));
+
+    // Nothing is left to withdraw.
+    try {
+      await program.methods
+        .withdrawAllFromFaucet()
+        .accounts({
+          mint: mint,
+          owner: user1.publicKey,
+          destination: user1.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc({ commitment: "confirmed" });
+      expect.fail("The transaction should have failed");
+    } catch (err: any) {
+      const code = err?.error?.errorCode?.code;
+      expect(code).to.equal("InsufficientFunds");
+    }
+  });
+
+  it("Owner closes faucet and reclaims treasury!", async () => {
+    const treasuryAccountBefore = await getAccount(
+      program.provider.connection,
+      treasuryAta
+    );
+    const user1AtaInfoBefore = await getAccount(
+      program.provider.connection,
+      user1Ata
+    );
+    const { poolGeneration } = await program.account.faucetConfig.fetch(
+      faucetPda
+    );
+
+    await program.methods
+      .closeFaucet()
+      .accounts({
+        mint: mint,
+        owner: user1.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+
+    const user1AtaInfoAfter = await getAccount(
+      program.provider.connection,
+      user1Ata
+    );
+    expect(user1AtaInfoAfter.amount).to.eql(
+      user1AtaInfoBefore.amount + treasuryAccountBefore.amount
+    );
+
+    const faucetInfo = await program.provider.connection.getAccountInfo(
+      faucetPda
+    );
+    const treasuryInfo = await program.provider.connection.getAccountInfo(
+      treasuryAta
+    );
+    expect(faucetInfo).to.be.null;
+    expect(treasuryInfo).to.be.null;
+
+    // Depositor records survive the close, so a new faucet must not reuse their generation.
+    const poolCounter = await program.account.faucetPoolCounter.fetch(
+      getFaucetPoolCounterPDA(program)
+    );
+    expect(poolCounter.nextPoolGeneration).to.equal(poolGeneration + 1);
+  });
+
+  it("Runs a Token-2022 transfer-fee faucet and closes it with fees withheld!", async () => {
+    const FEE_BPS = 100;
+    const CLAIM_AMOUNT = BigInt(1_000_000_000);
+    const connection = program.provider.connection;
+    // Token-2022 rounds transfer fees up.
+    const feeOf = (amount: bigint) =>
+      (amount * BigInt(FEE_BPS) + BigInt(9_999)) / BigInt(10_000);
+
+    const feeMint = await createTransferFeeMint(
+      FEE_BPS,
+      BigInt(1_000_000_000_000)
+    );
+    const ownerAta = (
+      await getOrCreateAssociatedTokenAccount(
+        connection,
+        user1.payer,
+        feeMint,
+        user1.publicKey,
+        false,
+        undefined,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      )
+    ).address;
+    await mintTo(
+      connection,
+      user1.payer,
+      feeMint,
+      ownerAta,
+      user1.publicKey,
+      BigInt(10_000_000_000),
+      [],
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    await program.methods
+      .initializeFaucet()
+      .accounts({
+        mint: feeMint,
+        owner: user1.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+    const { nextPoolGeneration } = await program.account.faucetPoolCounter.fetch(
+      getFaucetPoolCounterPDA(program)
+    );
+    expect(
+      (await program.account.faucetConfig.fetch(getFaucetPDA(program)))
+        .poolGeneration
+    ).to.equal(nextPoolGeneration);
+
+    await program.methods
+      .depositToFaucet(new BN(5_000_000_000))
+      .accounts({
+        mint: feeMint,
+        depositor: user1.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+
+    // A wallet that never claimed, so no earlier recipient record is on cooldown.
+    const claimer = anchor.web3.Keypair.generate();
+    await airdropSol(claimer.publicKey, 1);
+    const claimerAta = (
+      await getOrCreateAssociatedTokenAccount(
+        connection,
+        claimer,
+        feeMint,
+        claimer.publicKey,
+        false,
+        undefined,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      )
+    ).address;
+
+    await program.methods
+      .claimFromFaucet()
+      .accounts({
+        mint: feeMint,
+        recipient: claimer.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([claimer])
+      .rpc();
+
+    const claimerAtaInfo = await getAccount(
+      connection,
+      claimerAta,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    expect(claimerAtaInfo.amount).to.eql(CLAIM_AMOUNT);
+
+    const feeTreasuryAta = await getAssociatedTokenAddress(
+      feeMint,
+      faucetPda,
+      true,
+      TOKEN_2022_PROGRAM_ID
+    );
+    const treasuryBefore = await getAccount(
+      connection,
+      feeTreasuryAta,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    const ownerAtaBefore = await getAccount(
+      connection,
+      ownerAta,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    // The treasury still holds the fee withheld from the deposit; closing must harvest it.
+    await program.methods
+      .closeFaucet()
+      .accounts({
+        mint: feeMint,
+        owner: user1.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+
+    const ownerAtaAfter = await getAccount(
+      connection,
+      ownerAta,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    expect(ownerAtaAfter.amount).to.eql(
+      ownerAtaBefore.amount +
+        treasuryBefore.amount -
+        feeOf(treasuryBefore.amount)
+    );
+    expect(await connection.getAccountInfo(feeTreasuryAta)).to.be.null;
+  });
 });
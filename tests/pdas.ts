@@ -15,9 +15,109 @@ export function getTreasuryPDA(program: anchor.Program<Solcraft>) {
   )[0];
 }
 
+export function getFeeMintConfigPDA(
+  program: anchor.Program<Solcraft>,
+  mint: anchor.web3.PublicKey
+) {
+  return anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("fee_mint_config"), mint.toBuffer()],
+    program.programId
+  )[0];
+}
+
+export function getFeeDiscountPDA(
+  program: anchor.Program<Solcraft>,
+  wallet: anchor.web3.PublicKey
+) {
+  return anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("fee_discount"), wallet.toBuffer()],
+    program.programId
+  )[0];
+}
+
+export function getPendingActionPDA(
+  program: anchor.Program<Solcraft>,
+  actionId: anchor.BN
+) {
+  return anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("pending_action"), actionId.toArrayLike(Buffer, "le", 8)],
+    program.programId
+  )[0];
+}
+
+export function getProposalPDA(
+  program: anchor.Program<Solcraft>,
+  proposalId: anchor.BN
+) {
+  return anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+    program.programId
+  )[0];
+}
+
+export function getFactoryRolePDA(
+  program: anchor.Program<Solcraft>,
+  holder: anchor.web3.PublicKey,
+  role: number
+) {
+  return anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("factory_role"), holder.toBuffer(), Buffer.from([role])],
+    program.programId
+  )[0];
+}
+
 export function getFaucetPDA(program: anchor.Program<Solcraft>) {
   return anchor.web3.PublicKey.findProgramAddressSync(
     [Buffer.from("faucet_config")],
     program.programId
   )[0];
 }
+
+export function getFaucetRecipientPDA(
+  program: anchor.Program<Solcraft>,
+  recipient: anchor.web3.PublicKey
+) {
+  return anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("faucet_recipient"), recipient.toBuffer()],
+    program.programId
+  )[0];
+}
+
+export function getFaucetDepositorPDA(
+  program: anchor.Program<Solcraft>,
+  faucet: anchor.web3.PublicKey,
+  depositor: anchor.web3.PublicKey
+) {
+  return anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("faucet_depositor"), faucet.toBuffer(), depositor.toBuffer()],
+    program.programId
+  )[0];
+}
+
+export function getFaucetPoolCounterPDA(program: anchor.Program<Solcraft>) {
+  return anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("faucet_pool_counter")],
+    program.programId
+  )[0];
+}
+
+export function getSolFaucetPDA(program: anchor.Program<Solcraft>) {
+  return anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("sol_faucet_config")],
+    program.programId
+  )[0];
+}
+
+export function getSolFaucetVaultPDA(program: anchor.Program<Solcraft>) {
+  return anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("sol_faucet_vault")],
+    program.programId
+  )[0];
+}
+
+export function getBundleFaucetPDA(program: anchor.Program<Solcraft>) {
+  return anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("bundle_faucet_config")],
+    program.programId
+  )[0];
+}
@@ -1,5 +1,12 @@
 import * as anchor from "@coral-xyz/anchor";
-import { Program } from "@coral-xyz/anchor";
+import { BN, Program } from "@coral-xyz/anchor";
+import {
+  createInitializeMintInstruction,
+  createInitializeTransferFeeConfigInstruction,
+  ExtensionType,
+  getMintLen,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
 import { Solcraft } from "../target/types/solcraft";
 
 export const provider = anchor.AnchorProvider.env();
@@ -22,3 +29,71 @@ export async function airdropSol(
 
   await provider.connection.confirmTransaction(sign, "confirmed");
 }
+
+const BPF_LOADER_UPGRADEABLE_ID = new anchor.web3.PublicKey(
+  "BPFLoaderUpgradeab1e11111111111111111111111"
+);
+
+// Every instruction checks the guardian's ProgramState, so each suite makes sure it exists.
+export async function initializeProgramState() {
+  const [programState] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("program_state")],
+    program.programId
+  );
+  if (await provider.connection.getAccountInfo(programState)) {
+    return;
+  }
+
+  const [programData] = anchor.web3.PublicKey.findProgramAddressSync(
+    [program.programId.toBuffer()],
+    BPF_LOADER_UPGRADEABLE_ID
+  );
+
+  await program.methods
+    .initializeProgramState(user1.publicKey, new BN(3600))
+    .accountsPartial({
+      programData,
+      authority: user1.publicKey,
+    })
+    .rpc();
+}
+
+// Creates a Token-2022 mint with the transfer-fee extension, with `user1` as every authority.
+export async function createTransferFeeMint(
+  feeBasisPoints: number,
+  maxFee: bigint,
+  decimals = 6
+) {
+  const mint = anchor.web3.Keypair.generate();
+  const mintLen = getMintLen([ExtensionType.TransferFeeConfig]);
+  const lamports =
+    await provider.connection.getMinimumBalanceForRentExemption(mintLen);
+
+  const tx = new anchor.web3.Transaction().add(
+    anchor.web3.SystemProgram.createAccount({
+      fromPubkey: user1.publicKey,
+      newAccountPubkey: mint.publicKey,
+      space: mintLen,
+      lamports,
+      programId: TOKEN_2022_PROGRAM_ID,
+    }),
+    createInitializeTransferFeeConfigInstruction(
+      mint.publicKey,
+      user1.publicKey,
+      user1.publicKey,
+      feeBasisPoints,
+      maxFee,
+      TOKEN_2022_PROGRAM_ID
+    ),
+    createInitializeMintInstruction(
+      mint.publicKey,
+      decimals,
+      user1.publicKey,
+      null,
+      TOKEN_2022_PROGRAM_ID
+    )
+  );
+  await provider.sendAndConfirm(tx, [mint]);
+
+  return mint.publicKey;
+}
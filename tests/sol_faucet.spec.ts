@@ -0,0 +1,124 @@
+import { BN } from "bn.js";
+import { expect } from "chai";
+import * as anchor from "@coral-xyz/anchor";
+import {
+  airdropSol,
+  initializeProgramState,
+  user1,
+  user2,
+  program,
+} from "./setup";
+import { getSolFaucetPDA, getSolFaucetVaultPDA } from "./pdas";
+
+describe("SOL Faucet", () => {
+  const LAMPORTS_PER_SOL = anchor.web3.LAMPORTS_PER_SOL;
+  let vaultPda: anchor.web3.PublicKey;
+
+  before(async () => {
+    // Airdrop some SOL to the users before tests
+    await airdropSol(user1.publicKey, 10);
+    await airdropSol(user2.publicKey, 2);
+    await initializeProgramState();
+  });
+
+  it("Initialized!", async () => {
+    vaultPda = getSolFaucetVaultPDA(program);
+
+    await program.methods
+      .initializeSolFaucet()
+      .accounts({
+        owner: user1.publicKey,
+      })
+      .rpc();
+
+    const solFaucetConfig = await program.account.solFaucetConfig.fetch(
+      getSolFaucetPDA(program)
+    );
+
+    expect(solFaucetConfig.owner.toBase58()).to.eql(user1.publicKey.toBase58());
+    expect(solFaucetConfig.vault.toBase58()).to.eql(vaultPda.toBase58());
+    expect(solFaucetConfig.allowedClaimLamports.toNumber()).to.equal(
+      LAMPORTS_PER_SOL
+    );
+  });
+
+  it("Deposit SOL into faucet!", async () => {
+    const DEPOSIT_AMOUNT = new BN(5 * LAMPORTS_PER_SOL);
+    const vaultBalanceBefore = await program.provider.connection.getBalance(
+      vaultPda
+    );
+
+    await program.methods
+      .depositToSolFaucet(DEPOSIT_AMOUNT)
+      .accounts({
+        depositor: user1.publicKey,
+      })
+      .rpc();
+
+    const vaultBalanceAfter = await program.provider.connection.getBalance(
+      vaultPda
+    );
+
+    expect(vaultBalanceAfter - vaultBalanceBefore).to.equal(
+      DEPOSIT_AMOUNT.toNumber()
+    );
+  });
+
+  it("Withdraw SOL from faucet!", async () => {
+    const WITHDRAW_AMOUNT = new BN(2 * LAMPORTS_PER_SOL);
+    const vaultBalanceBefore = await program.provider.connection.getBalance(
+      vaultPda
+    );
+
+    await program.methods
+      .withdrawFromSolFaucet(WITHDRAW_AMOUNT)
+      .accounts({
+        recipient: user1.publicKey,
+      })
+      .rpc();
+
+    const vaultBalanceAfter = await program.provider.connection.getBalance(
+      vaultPda
+    );
+
+    expect(vaultBalanceBefore - vaultBalanceAfter).to.equal(
+      WITHDRAW_AMOUNT.toNumber()
+    );
+  });
+
+  it("Any User can claim SOL from faucet!", async () => {
+    const vaultBalanceBefore = await program.provider.connection.getBalance(
+      vaultPda
+    );
+
+    await program.methods
+      .claimFromSolFaucet()
+      .accounts({
+        recipient: user2.publicKey,
+      })
+      .signers([user2])
+      .rpc();
+
+    const vaultBalanceAfter = await program.provider.connection.getBalance(
+      vaultPda
+    );
+
+    expect(vaultBalanceBefore - vaultBalanceAfter).to.equal(LAMPORTS_PER_SOL);
+  });
+
+  it("Fails to claim SOL again before cooldown elapses!", async () => {
+    try {
+      await program.methods
+        .claimFromSolFaucet()
+        .accounts({
+          recipient: user2.publicKey,
+        })
+        .signers([user2])
+        .rpc({ commitment: "confirmed" });
+      expect.fail("The transaction should have failed");
+    } catch (err: any) {
+      const code = err?.error?.errorCode?.code;
+      expect(code).to.equal("CooldownNotElapsed");
+    }
+  });
+});
@@ -1,13 +1,25 @@
 import {
+  createMint,
   getAssociatedTokenAddress,
   getAccount,
   getMint,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
 } from "@solana/spl-token";
 import { BN } from "bn.js";
 import * as anchor from "@coral-xyz/anchor";
-import { airdropSol, program, provider, user1, user2 } from "./setup";
+import {
+  airdropSol,
+  initializeProgramState,
+  LAMPORTS_FEE,
+  program,
+  provider,
+  user1,
+  user2,
+} from "./setup";
 import { TOKEN_PROGRAM_ID } from "@coral-xyz/anchor/dist/cjs/utils/token";
 import { expect } from "chai";
+import { getFactoryPDA, getFeeMintConfigPDA, getTreasuryPDA } from "./pdas";
 
 describe("Token", () => {
   let mintPubKey: anchor.web3.PublicKey;
@@ -19,11 +31,15 @@ describe("Token", () => {
   const TOKEN_INITIAL_SUPPLY_BN = new BN(TOKEN_INITIAL_SUPPLY).mul(
     new BN(10).pow(new BN(TOKEN_DECIMALS))
   );
+  const MAX_FEE_LAMPORTS = new BN(LAMPORTS_FEE * 2);
+  // Only checked when paying in an SPL fee mint.
+  const MAX_FEE_AMOUNT = new BN(0);
 
   before(async () => {
     // Airdrop some SOL to the user1 before tests
     await airdropSol(user1.publicKey, 2);
     await airdropSol(user2.publicKey, 1);
+    await initializeProgramState();
   });
 
   it("Creates token and pays fee to factory treasury!", async () => {
@@ -38,7 +54,9 @@ describe("Token", () => {
         TOKEN_SYMBOL,
         TOKEN_URI,
         TOKEN_DECIMALS,
-        TOKEN_INITIAL_SUPPLY_BN
+        TOKEN_INITIAL_SUPPLY_BN,
+        MAX_FEE_LAMPORTS,
+        MAX_FEE_AMOUNT
       )
       .accounts({
         mint: mint.publicKey,
@@ -60,6 +78,259 @@ describe("Token", () => {
     );
   });
 
+  it("Fails to create a token when the fee exceeds the payer's maximum!", async () => {
+    const mint = anchor.web3.Keypair.generate();
+
+    try {
+      await program.methods
+        .createToken(
+          TOKEN_NAME,
+          TOKEN_SYMBOL,
+          "https://example.com/token-metadata.json",
+          TOKEN_DECIMALS,
+          TOKEN_INITIAL_SUPPLY_BN,
+          new BN(LAMPORTS_FEE - 1),
+          MAX_FEE_AMOUNT
+        )
+        .accounts({
+          mint: mint.publicKey,
+          payer: user1.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([mint])
+        .rpc();
+      expect.fail("The transaction should have failed");
+    } catch (err: any) {
+      expect(err?.error?.errorCode?.code).to.equal("FeeExceedsMax");
+    }
+  });
+
+  it("Pays the referral share of the creation fee to the referrer!", async () => {
+    const REFERRAL_FEE_BPS = 1_000;
+
+    await program.methods
+      .updateReferralFee(REFERRAL_FEE_BPS)
+      .accounts({
+        admin: user1.publicKey,
+      })
+      .rpc();
+
+    const factoryConfig = await program.account.factoryConfig.fetch(
+      getFactoryPDA(program)
+    );
+    const expectedReferralFee = factoryConfig.creationFeeLamports
+      .muln(REFERRAL_FEE_BPS)
+      .divn(10_000)
+      .toNumber();
+    const referrerBalanceBefore = await provider.connection.getBalance(
+      user2.publicKey
+    );
+
+    const mint = anchor.web3.Keypair.generate();
+    await program.methods
+      .createToken(
+        TOKEN_NAME,
+        TOKEN_SYMBOL,
+        "https://example.com/token-metadata.json",
+        TOKEN_DECIMALS,
+        TOKEN_INITIAL_SUPPLY_BN,
+        MAX_FEE_LAMPORTS,
+        MAX_FEE_AMOUNT
+      )
+      .accounts({
+        mint: mint.publicKey,
+        payer: user1.publicKey,
+        referrer: user2.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([mint])
+      .rpc();
+
+    const referrerBalanceAfter = await provider.connection.getBalance(
+      user2.publicKey
+    );
+    expect(referrerBalanceAfter - referrerBalanceBefore).to.equal(
+      expectedReferralFee
+    );
+  });
+
+  it("Keeps a referral share that would not leave the referrer rent-exempt!", async () => {
+    // One basis point of the fee is far below the rent-exempt minimum of an empty wallet.
+    await program.methods
+      .updateReferralFee(1)
+      .accounts({
+        admin: user1.publicKey,
+      })
+      .rpc();
+
+    const treasuryPDA = getTreasuryPDA(program);
+    const referrer = anchor.web3.Keypair.generate().publicKey;
+    const { creationFeeLamports } = await program.account.factoryConfig.fetch(
+      getFactoryPDA(program)
+    );
+    const treasuryBalanceBefore = await provider.connection.getBalance(
+      treasuryPDA
+    );
+
+    const mint = anchor.web3.Keypair.generate();
+    await program.methods
+      .createToken(
+        TOKEN_NAME,
+        TOKEN_SYMBOL,
+        "https://example.com/token-metadata.json",
+        TOKEN_DECIMALS,
+        TOKEN_INITIAL_SUPPLY_BN,
+        MAX_FEE_LAMPORTS,
+        MAX_FEE_AMOUNT
+      )
+      .accounts({
+        mint: mint.publicKey,
+        payer: user1.publicKey,
+        referrer,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([mint])
+      .rpc();
+
+    expect(await provider.connection.getBalance(referrer)).to.equal(0);
+    expect(
+      (await provider.connection.getBalance(treasuryPDA)) -
+        treasuryBalanceBefore
+    ).to.equal(creationFeeLamports.toNumber());
+  });
+
+  it("Pays the creation fee in an SPL token and sweeps it from the treasury!", async () => {
+    const FEE_PRICE = BigInt(5_000_000);
+    const connection = provider.connection;
+    const treasuryPDA = getTreasuryPDA(program);
+
+    const feeMint = await createMint(
+      connection,
+      user1.payer,
+      user1.publicKey,
+      null,
+      6
+    );
+    await program.methods
+      .setFeeMint(new BN(FEE_PRICE.toString()), true)
+      .accounts({
+        feeMint,
+        admin: user1.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+
+    const payerFeeAta = (
+      await getOrCreateAssociatedTokenAccount(
+        connection,
+        user1.payer,
+        feeMint,
+        user1.publicKey
+      )
+    ).address;
+    await mintTo(
+      connection,
+      user1.payer,
+      feeMint,
+      payerFeeAta,
+      user1.publicKey,
+      FEE_PRICE
+    );
+    const treasuryFeeAta = await getAssociatedTokenAddress(
+      feeMint,
+      treasuryPDA,
+      true
+    );
+    const treasuryLamportsBefore = await connection.getBalance(treasuryPDA);
+
+    const feeAccounts = {
+      payer: user1.publicKey,
+      feeMintConfig: getFeeMintConfigPDA(program, feeMint),
+      feeMint,
+      payerFeeAta,
+      treasuryFeeAta,
+      feeTokenProgram: TOKEN_PROGRAM_ID,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    };
+
+    const rejectedMint = anchor.web3.Keypair.generate();
+    try {
+      await program.methods
+        .createToken(
+          TOKEN_NAME,
+          TOKEN_SYMBOL,
+          "https://example.com/token-metadata.json",
+          TOKEN_DECIMALS,
+          TOKEN_INITIAL_SUPPLY_BN,
+          MAX_FEE_LAMPORTS,
+          new BN((FEE_PRICE - BigInt(1)).toString())
+        )
+        .accounts({ mint: rejectedMint.publicKey, ...feeAccounts })
+        .signers([rejectedMint])
+        .rpc();
+      expect.fail("The transaction should have failed");
+    } catch (err: any) {
+      expect(err?.error?.errorCode?.code).to.equal("FeeExceedsMax");
+    }
+
+    const mint = anchor.web3.Keypair.generate();
+    await program.methods
+      .createToken(
+        TOKEN_NAME,
+        TOKEN_SYMBOL,
+        "https://example.com/token-metadata.json",
+        TOKEN_DECIMALS,
+        TOKEN_INITIAL_SUPPLY_BN,
+        MAX_FEE_LAMPORTS,
+        new BN(FEE_PRICE.toString())
+      )
+      .accounts({ mint: mint.publicKey, ...feeAccounts })
+      .signers([mint])
+      .rpc();
+
+    // The fee is paid entirely in tokens; no lamports reach the treasury.
+    expect((await getAccount(connection, treasuryFeeAta)).amount).to.eql(
+      FEE_PRICE
+    );
+    expect((await getAccount(connection, payerFeeAta)).amount).to.eql(
+      BigInt(0)
+    );
+    expect(await connection.getBalance(treasuryPDA)).to.equal(
+      treasuryLamportsBefore
+    );
+
+    const coldWallet = anchor.web3.Keypair.generate().publicKey;
+    const coldWalletFeeAta = (
+      await getOrCreateAssociatedTokenAccount(
+        connection,
+        user1.payer,
+        feeMint,
+        coldWallet
+      )
+    ).address;
+
+    await program.methods
+      .withdrawFees(null)
+      .accounts({
+        destination: coldWallet,
+        authority: user1.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .remainingAccounts([
+        { pubkey: feeMint, isSigner: false, isWritable: false },
+        { pubkey: treasuryFeeAta, isSigner: false, isWritable: true },
+        { pubkey: coldWalletFeeAta, isSigner: false, isWritable: true },
+      ])
+      .rpc();
+
+    expect((await getAccount(connection, coldWalletFeeAta)).amount).to.eql(
+      FEE_PRICE
+    );
+    expect((await getAccount(connection, treasuryFeeAta)).amount).to.eql(
+      BigInt(0)
+    );
+  });
+
   it("Mint more tokens to token admin", async () => {
     const payer_ata = await getAssociatedTokenAddress(
       mintPubKey,